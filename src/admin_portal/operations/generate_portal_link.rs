@@ -3,10 +3,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::admin_portal::AdminPortal;
 use crate::organizations::OrganizationId;
-use crate::{ResponseExt, WorkOsResult};
+use crate::{RequestExt, ResponseExt, Timestamp, WorkOsResult};
 
 /// The intent of an Admin Portal session.
 #[derive(Debug, Serialize)]
+#[non_exhaustive]
 #[serde(rename_all = "snake_case")]
 pub enum AdminPortalIntent {
     /// The Admin Portal will be used to setup Single Sign-On (SSO).
@@ -50,6 +51,12 @@ pub struct GeneratePortalLinkParams<'a> {
 pub struct GeneratePortalLinkResponse {
     /// The generate Admin Portal link.
     pub link: String,
+
+    /// When the link expires, if the API returned it.
+    ///
+    /// Links are valid for five minutes after they are generated, so a new link
+    /// should be generated each time a user is sent to the Admin Portal.
+    pub expires_at: Option<Timestamp>,
 }
 
 /// An error returned from [`GeneratePortalLink`].
@@ -61,6 +68,9 @@ pub enum GeneratePortalLinkError {}
 pub trait GeneratePortalLink {
     /// Generates an Admin Portal link.
     ///
+    /// Admin Portal links are short-lived. Generate a new link each time a user
+    /// opens the Admin Portal rather than storing it.
+    ///
     /// [WorkOS Docs: Generate a Portal Link](https://workos.com/docs/reference/admin-portal/portal-link/generate)
     ///
     /// # Examples
@@ -74,7 +84,7 @@ pub trait GeneratePortalLink {
     /// # async fn run() -> WorkOsResult<(), GeneratePortalLinkError> {
     /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
     ///
-    /// let GeneratePortalLinkResponse { link } = workos
+    /// let GeneratePortalLinkResponse { link, .. } = workos
     ///     .admin_portal()
     ///     .generate_portal_link(&GeneratePortalLinkParams {
     ///         target: &AdminPortalTarget::Organization {
@@ -106,10 +116,11 @@ impl<'a> GeneratePortalLink for AdminPortal<'a> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_instrumented(self.workos)
             .await?
-            .handle_unauthorized_or_generic_error()?
-            .json::<GeneratePortalLinkResponse>()
+            .handle_unauthorized_or_generic_error()
+            .await?
+            .parse_json::<GeneratePortalLinkResponse, _>()
             .await?;
 
         Ok(generate_link_response)
@@ -148,7 +159,7 @@ mod test {
             .unwrap()
             .build();
 
-        let GeneratePortalLinkResponse { link } = workos
+        let GeneratePortalLinkResponse { link, .. } = workos
             .admin_portal()
             .generate_portal_link(&GeneratePortalLinkParams {
                 target: &AdminPortalTarget::Organization {
@@ -165,4 +176,28 @@ mod test {
             "https://setup.workos.com/portal/launch?secret=JteZqfJZqUcgWGaYCC6iI0gW0".to_string()
         )
     }
+
+    #[test]
+    fn it_deserializes_the_expiry_when_it_is_returned() {
+        let response: GeneratePortalLinkResponse = serde_json::from_value(json!({
+            "link": "https://setup.workos.com/portal/launch?secret=JteZqfJZqUcgWGaYCC6iI0gW0",
+            "expires_at": "2021-06-25T19:12:33.155Z"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response.expires_at,
+            Some(Timestamp::try_from("2021-06-25T19:12:33.155Z").unwrap())
+        )
+    }
+
+    #[test]
+    fn it_deserializes_a_response_without_an_expiry() {
+        let response: GeneratePortalLinkResponse = serde_json::from_value(json!({
+            "link": "https://setup.workos.com/portal/launch?secret=JteZqfJZqUcgWGaYCC6iI0gW0"
+        }))
+        .unwrap();
+
+        assert_eq!(response.expires_at, None)
+    }
 }
@@ -1,7 +1,21 @@
+mod concurrency;
+#[cfg(feature = "crypto")]
+pub(crate) mod crypto;
 mod error;
+mod observer;
+mod pagination;
+mod request;
 mod response;
+mod retry;
+mod strict;
 mod types;
 
+pub use concurrency::*;
 pub use error::*;
+pub use observer::*;
+pub(crate) use pagination::*;
+pub(crate) use request::*;
 pub(crate) use response::*;
+pub use retry::*;
+pub use strict::*;
 pub use types::*;
@@ -0,0 +1,163 @@
+use std::future::Future;
+
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+
+/// The default number of operations to run concurrently when using
+/// [`join_with_limit`] or [`try_join_with_limit`].
+///
+/// This is intentionally conservative to avoid tripping the WorkOS API rate limits
+/// when issuing many requests at once.
+pub const DEFAULT_CONCURRENCY_LIMIT: usize = 8;
+
+/// Runs the given futures with at most `limit` of them in flight at once and
+/// collects their outputs.
+///
+/// The outputs are returned in the same order as the futures were provided,
+/// regardless of the order in which they complete. A `limit` of `0` is treated as `1`.
+///
+/// # Examples
+///
+/// ```
+/// use workos::{join_with_limit, DEFAULT_CONCURRENCY_LIMIT};
+///
+/// # async fn run() {
+/// let results = join_with_limit(
+///     (1..=3).map(|n| async move { n * 2 }),
+///     DEFAULT_CONCURRENCY_LIMIT,
+/// )
+/// .await;
+///
+/// assert_eq!(results, vec![2, 4, 6]);
+/// # }
+/// ```
+pub async fn join_with_limit<I, F, T>(futures: I, limit: usize) -> Vec<T>
+where
+    I: IntoIterator<Item = F>,
+    F: Future<Output = T>,
+{
+    stream::iter(futures).buffered(limit.max(1)).collect().await
+}
+
+/// Runs the given fallible futures with at most `limit` of them in flight at once
+/// and collects their outputs.
+///
+/// The outputs are returned in the same order as the futures were provided.
+/// If any future fails, the first error (in the order the futures were provided)
+/// is returned and no further futures are started. A `limit` of `0` is treated as `1`.
+///
+/// # Examples
+///
+/// ```
+/// # use workos::WorkOsResult;
+/// # use workos::directory_sync::*;
+/// use workos::{try_join_with_limit, ApiKey, WorkOs, DEFAULT_CONCURRENCY_LIMIT};
+///
+/// # async fn run() -> WorkOsResult<(), GetDirectoryError> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+/// let directory_sync = workos.directory_sync();
+///
+/// let ids = [
+///     DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+///     DirectoryId::from("directory_01E8CS3GSBEBZ1F1CZAEE3KHDG"),
+/// ];
+///
+/// let directories = try_join_with_limit(
+///     ids.iter().map(|id| directory_sync.get_directory(id)),
+///     DEFAULT_CONCURRENCY_LIMIT,
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn try_join_with_limit<I, F, T, E>(futures: I, limit: usize) -> Result<Vec<T>, E>
+where
+    I: IntoIterator<Item = F>,
+    F: Future<Output = Result<T, E>>,
+{
+    stream::iter(futures)
+        .buffered(limit.max(1))
+        .try_collect()
+        .await
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    struct InFlight {
+        current: AtomicUsize,
+        max: AtomicUsize,
+        started: AtomicUsize,
+    }
+
+    impl InFlight {
+        fn new() -> Self {
+            Self {
+                current: AtomicUsize::new(0),
+                max: AtomicUsize::new(0),
+                started: AtomicUsize::new(0),
+            }
+        }
+
+        async fn run<T>(&self, value: T, yields: usize) -> T {
+            self.started.fetch_add(1, Ordering::SeqCst);
+            let current = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max.fetch_max(current, Ordering::SeqCst);
+
+            for _ in 0..yields {
+                tokio::task::yield_now().await;
+            }
+
+            self.current.fetch_sub(1, Ordering::SeqCst);
+            value
+        }
+    }
+
+    #[tokio::test]
+    async fn it_preserves_the_order_of_the_futures() {
+        let in_flight = InFlight::new();
+
+        let results = join_with_limit(
+            (0..10).map(|n| in_flight.run(n, 10 - n)),
+            DEFAULT_CONCURRENCY_LIMIT,
+        )
+        .await;
+
+        assert_eq!(results, (0..10).collect::<Vec<_>>())
+    }
+
+    #[tokio::test]
+    async fn it_does_not_exceed_the_concurrency_limit() {
+        let in_flight = InFlight::new();
+
+        join_with_limit((0..20).map(|n| in_flight.run(n, 3)), 4).await;
+
+        assert_eq!(in_flight.max.load(Ordering::SeqCst), 4)
+    }
+
+    #[tokio::test]
+    async fn it_treats_a_limit_of_zero_as_one() {
+        let in_flight = InFlight::new();
+
+        let results = join_with_limit((0..3).map(|n| in_flight.run(n, 1)), 0).await;
+
+        assert_eq!(results, vec![0, 1, 2]);
+        assert_eq!(in_flight.max.load(Ordering::SeqCst), 1)
+    }
+
+    #[tokio::test]
+    async fn it_returns_the_first_error_and_stops_starting_futures() {
+        let in_flight = InFlight::new();
+
+        let result = try_join_with_limit(
+            (0..10).map(|n| in_flight.run(if n == 2 { Err(n) } else { Ok(n) }, 1)),
+            1,
+        )
+        .await;
+
+        assert_eq!(result, Err(2));
+        assert_eq!(in_flight.started.load(Ordering::SeqCst), 3)
+    }
+}
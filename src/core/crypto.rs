@@ -0,0 +1,231 @@
+//! Cryptographic primitives, enabled by the `crypto` feature. They are backed by `ring`
+//! when the `rustls-tls` feature is enabled and by OpenSSL when only the `native-tls`
+//! feature is enabled.
+
+#[cfg(not(any(feature = "ring", feature = "openssl")))]
+compile_error!("the `crypto` feature requires the `rustls-tls` or `native-tls` feature");
+
+/// The hash function used by an [`Hmac`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HmacAlgorithm {
+    /// SHA-1, which is only used where a protocol requires it, such as TOTP.
+    #[cfg_attr(not(feature = "testing"), allow(dead_code))]
+    Sha1,
+
+    /// SHA-256.
+    Sha256,
+
+    /// SHA-512, which is only used where a protocol allows it, such as TOTP.
+    #[cfg_attr(not(feature = "testing"), allow(dead_code))]
+    Sha512,
+}
+
+/// A prepared HMAC key that can be reused to compute many HMACs without repeating
+/// the key setup.
+#[derive(Clone)]
+pub(crate) struct HmacKey {
+    #[cfg(feature = "ring")]
+    key: ring::hmac::Key,
+
+    #[cfg(all(feature = "openssl", not(feature = "ring")))]
+    digest: openssl::hash::MessageDigest,
+
+    #[cfg(all(feature = "openssl", not(feature = "ring")))]
+    key: openssl::pkey::PKey<openssl::pkey::Private>,
+}
+
+impl HmacKey {
+    /// Prepares a key for the given algorithm.
+    #[cfg(feature = "ring")]
+    pub fn new(algorithm: HmacAlgorithm, key: &[u8]) -> Self {
+        let algorithm = match algorithm {
+            HmacAlgorithm::Sha1 => ring::hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY,
+            HmacAlgorithm::Sha256 => ring::hmac::HMAC_SHA256,
+            HmacAlgorithm::Sha512 => ring::hmac::HMAC_SHA512,
+        };
+
+        Self {
+            key: ring::hmac::Key::new(algorithm, key),
+        }
+    }
+
+    /// Prepares a key for the given algorithm.
+    #[cfg(all(feature = "openssl", not(feature = "ring")))]
+    pub fn new(algorithm: HmacAlgorithm, key: &[u8]) -> Self {
+        use openssl::hash::MessageDigest;
+        use openssl::pkey::PKey;
+
+        let digest = match algorithm {
+            HmacAlgorithm::Sha1 => MessageDigest::sha1(),
+            HmacAlgorithm::Sha256 => MessageDigest::sha256(),
+            HmacAlgorithm::Sha512 => MessageDigest::sha512(),
+        };
+
+        Self {
+            digest,
+            key: PKey::hmac(key).expect("HMAC keys are supported"),
+        }
+    }
+}
+
+/// An incremental HMAC computation.
+pub(crate) struct Hmac {
+    #[cfg(feature = "ring")]
+    context: ring::hmac::Context,
+
+    #[cfg(all(feature = "openssl", not(feature = "ring")))]
+    key: HmacKey,
+
+    /// The message being authenticated.
+    ///
+    /// An OpenSSL `Signer` borrows its key, so the message is collected and signed
+    /// when the computation is completed.
+    #[cfg(all(feature = "openssl", not(feature = "ring")))]
+    message: Vec<u8>,
+}
+
+impl Hmac {
+    /// Begins computing an HMAC with the given algorithm and key.
+    pub fn new(algorithm: HmacAlgorithm, key: &[u8]) -> Self {
+        Self::with_key(&HmacKey::new(algorithm, key))
+    }
+
+    /// Begins computing an HMAC with a prepared key.
+    #[cfg(feature = "ring")]
+    pub fn with_key(key: &HmacKey) -> Self {
+        Self {
+            context: ring::hmac::Context::with_key(&key.key),
+        }
+    }
+
+    /// Begins computing an HMAC with a prepared key.
+    #[cfg(all(feature = "openssl", not(feature = "ring")))]
+    pub fn with_key(key: &HmacKey) -> Self {
+        Self {
+            key: key.clone(),
+            message: Vec::new(),
+        }
+    }
+
+    /// Adds data to the message being authenticated.
+    pub fn update(&mut self, data: &[u8]) {
+        #[cfg(feature = "ring")]
+        self.context.update(data);
+
+        #[cfg(all(feature = "openssl", not(feature = "ring")))]
+        self.message.extend_from_slice(data);
+    }
+
+    /// Completes the computation and returns the tag.
+    pub fn finish(self) -> Vec<u8> {
+        #[cfg(feature = "ring")]
+        {
+            self.context.sign().as_ref().to_vec()
+        }
+
+        #[cfg(all(feature = "openssl", not(feature = "ring")))]
+        {
+            use openssl::sign::Signer;
+
+            let mut signer =
+                Signer::new(self.key.digest, &self.key.key).expect("digest is available");
+            signer.update(&self.message).expect("digest is available");
+            signer.sign_to_vec().expect("digest is available")
+        }
+    }
+
+    /// Completes the computation and compares the result with `tag` in constant time.
+    pub fn verify(self, tag: &[u8]) -> bool {
+        let expected = self.finish();
+
+        expected.len() == tag.len()
+            && expected
+                .iter()
+                .zip(tag)
+                .fold(0, |difference, (a, b)| difference | (a ^ b))
+                == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Hmac, HmacAlgorithm, HmacKey};
+
+    // RFC 4231, test case 2.
+    const KEY: &[u8] = b"Jefe";
+    const DATA: &[u8] = b"what do ya want for nothing?";
+    const TAG: &str = "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843";
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn it_computes_an_hmac_sha256() {
+        let mut hmac = Hmac::new(HmacAlgorithm::Sha256, KEY);
+        hmac.update(DATA);
+
+        assert_eq!(hex(&hmac.finish()), TAG)
+    }
+
+    #[test]
+    fn it_computes_an_hmac_sha256_incrementally() {
+        let mut hmac = Hmac::new(HmacAlgorithm::Sha256, KEY);
+        for chunk in DATA.chunks(5) {
+            hmac.update(chunk);
+        }
+
+        assert_eq!(hex(&hmac.finish()), TAG)
+    }
+
+    #[test]
+    fn it_hashes_keys_longer_than_the_block_size() {
+        // RFC 4231, test case 6.
+        let mut hmac = Hmac::new(HmacAlgorithm::Sha256, &[0xaa; 131]);
+        hmac.update(b"Test Using Larger Than Block-Size Key - Hash Key First");
+
+        assert_eq!(
+            hex(&hmac.finish()),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        )
+    }
+
+    #[test]
+    fn it_computes_an_hmac_sha1() {
+        // RFC 2202, test case 2.
+        let mut hmac = Hmac::new(HmacAlgorithm::Sha1, KEY);
+        hmac.update(DATA);
+
+        assert_eq!(
+            hex(&hmac.finish()),
+            "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79"
+        )
+    }
+
+    #[test]
+    fn it_verifies_a_tag() {
+        let mut hmac = Hmac::new(HmacAlgorithm::Sha256, KEY);
+        hmac.update(DATA);
+        let tag = hmac.finish();
+
+        let mut hmac = Hmac::new(HmacAlgorithm::Sha256, KEY);
+        hmac.update(DATA);
+        assert!(hmac.verify(&tag));
+
+        let mut hmac = Hmac::new(HmacAlgorithm::Sha256, KEY);
+        hmac.update(b"what do ya want for something?");
+        assert!(!hmac.verify(&tag));
+    }
+
+    #[test]
+    fn it_reuses_a_prepared_key() {
+        let key = HmacKey::new(HmacAlgorithm::Sha256, KEY);
+
+        for _ in 0..2 {
+            let mut hmac = Hmac::with_key(&key);
+            hmac.update(DATA);
+
+            assert_eq!(hex(&hmac.finish()), TAG)
+        }
+    }
+}
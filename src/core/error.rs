@@ -1,16 +1,37 @@
+use std::time::Duration;
+
+use serde::Deserialize;
 use thiserror::Error;
 
+use crate::is_retryable_status;
+
 /// A WorkOS SDK error.
 #[derive(Debug, Error)]
 pub enum WorkOsError<E> {
     /// An error occurred with the current operation.
-    #[error("operational error")]
+    ///
+    /// Every operation error type implements [`Error`](std::error::Error), but the
+    /// operation error is included in the message rather than as the
+    /// [`source`](std::error::Error::source) of this error. Exposing it as the source
+    /// would require `E: Error` for `WorkOsError<E>` to implement `Error` at all, and
+    /// helpers that are not operations, such as
+    /// [`WorkOs::health_check`](crate::WorkOs::health_check), use the `()` default of
+    /// [`WorkOsResult`], which does not implement `Error`. Match on this variant to
+    /// access the operation error directly.
+    #[error("operational error: {0:?}")]
     Operation(E),
 
     /// An unauthorized response was received from the WorkOS API.
     #[error("unauthorized")]
     Unauthorized,
 
+    /// A forbidden response was received from the WorkOS API.
+    ///
+    /// Unlike [`Unauthorized`](Self::Unauthorized), this indicates that the API key is
+    /// valid but does not have permission to perform the request.
+    #[error("forbidden")]
+    Forbidden,
+
     /// An error occurred while parsing a URL.
     #[error("URL parse error")]
     UrlParseError(#[from] url::ParseError),
@@ -18,7 +39,288 @@ pub enum WorkOsError<E> {
     /// An unhandled error occurred with the API request.
     #[error("request error")]
     RequestError(#[from] reqwest::Error),
+
+    /// The WorkOS API is temporarily unavailable, for example during maintenance.
+    #[error("service unavailable")]
+    ServiceUnavailable {
+        /// How long to wait before retrying the request, if the API provided a
+        /// `Retry-After` header.
+        retry_after: Option<Duration>,
+    },
+
+    /// The request was rejected by the WorkOS API because it failed validation.
+    #[error("validation error")]
+    Validation(ValidationError),
+
+    /// The response body from the WorkOS API was larger than the limit set using
+    /// [`WorkOsBuilder::max_response_bytes`](crate::WorkOsBuilder::max_response_bytes).
+    #[error("response body exceeded the limit of {limit} bytes")]
+    ResponseTooLarge {
+        /// The maximum number of bytes that could be read.
+        limit: usize,
+    },
+
+    /// The response from the WorkOS API had an empty body where data was expected.
+    #[error("expected {context}, but the response body was empty")]
+    EmptyBody {
+        /// A description of what was being deserialized.
+        context: String,
+    },
+
+    /// The response from the WorkOS API could not be deserialized.
+    #[error("failed to deserialize {context}")]
+    Deserialize {
+        /// The underlying deserialization error.
+        source: serde_json::Error,
+
+        /// A description of what was being deserialized.
+        context: String,
+    },
+}
+
+impl<E> WorkOsError<E> {
+    /// Returns whether the failed request may succeed if it is retried.
+    ///
+    /// Requests that were rate limited, timed out, failed to connect, or received
+    /// a server error (5xx), including [`ServiceUnavailable`](Self::ServiceUnavailable),
+    /// are considered retryable. All other errors, including
+    /// client errors (4xx) and operation errors, are not.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::RequestError(err) => {
+                err.is_timeout()
+                    || err.is_connect()
+                    || err.status().is_some_and(is_retryable_status)
+            }
+            Self::ServiceUnavailable { .. } => true,
+            Self::Operation(_)
+            | Self::Unauthorized
+            | Self::Forbidden
+            | Self::UrlParseError(_)
+            | Self::Validation(_)
+            | Self::ResponseTooLarge { .. }
+            | Self::EmptyBody { .. }
+            | Self::Deserialize { .. } => false,
+        }
+    }
+
+    /// Converts the operation error using the provided function, leaving all other
+    /// errors unchanged.
+    pub(crate) fn map_operation<F>(self, f: impl FnOnce(E) -> F) -> WorkOsError<F> {
+        match self {
+            Self::Operation(err) => WorkOsError::Operation(f(err)),
+            Self::Unauthorized => WorkOsError::Unauthorized,
+            Self::Forbidden => WorkOsError::Forbidden,
+            Self::UrlParseError(err) => WorkOsError::UrlParseError(err),
+            Self::RequestError(err) => WorkOsError::RequestError(err),
+            Self::ServiceUnavailable { retry_after } => {
+                WorkOsError::ServiceUnavailable { retry_after }
+            }
+            Self::Validation(err) => WorkOsError::Validation(err),
+            Self::ResponseTooLarge { limit } => WorkOsError::ResponseTooLarge { limit },
+            Self::EmptyBody { context } => WorkOsError::EmptyBody { context },
+            Self::Deserialize { source, context } => WorkOsError::Deserialize { source, context },
+        }
+    }
+}
+
+/// A validation error returned from the WorkOS API.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ValidationError {
+    /// The error code.
+    pub code: Option<String>,
+
+    /// The error message.
+    pub message: Option<String>,
+
+    /// The errors for the individual fields that failed validation.
+    #[serde(default, rename = "errors")]
+    pub field_errors: Vec<FieldError>,
+}
+
+/// A validation error for an individual field.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct FieldError {
+    /// The name of the field that failed validation.
+    pub field: String,
+
+    /// The error code describing why the field failed validation.
+    pub code: String,
 }
 
 /// A WorkOS SDK result.
-pub type WorkOsResult<T, E> = Result<T, WorkOsError<E>>;
+///
+/// The error type defaults to `()` for results that do not have an
+/// [`Operation`](WorkOsError::Operation) error.
+///
+/// # Examples
+///
+/// ```
+/// use workos::{WorkOs, WorkOsResult};
+///
+/// async fn check_workos(workos: &WorkOs) -> WorkOsResult<()> {
+///     workos.health_check().await
+/// }
+/// ```
+pub type WorkOsResult<T, E = ()> = Result<T, WorkOsError<E>>;
+
+#[cfg(test)]
+mod test {
+    use std::error::Error;
+    use std::net::TcpListener;
+    use std::time::Duration;
+
+    use super::*;
+
+    async fn request_error_for_status(status: usize) -> WorkOsError<()> {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/").with_status(status).create();
+
+        let err = reqwest::get(server.url())
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap_err();
+
+        WorkOsError::RequestError(err)
+    }
+
+    #[tokio::test]
+    async fn it_considers_rate_limited_errors_retryable() {
+        assert!(request_error_for_status(429).await.is_retryable())
+    }
+
+    #[tokio::test]
+    async fn it_considers_server_errors_retryable() {
+        assert!(request_error_for_status(500).await.is_retryable());
+        assert!(request_error_for_status(503).await.is_retryable())
+    }
+
+    #[tokio::test]
+    async fn it_does_not_consider_client_errors_retryable() {
+        assert!(!request_error_for_status(400).await.is_retryable());
+        assert!(!request_error_for_status(404).await.is_retryable())
+    }
+
+    #[tokio::test]
+    async fn it_considers_connect_errors_retryable() {
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let err = reqwest::get(format!("http://127.0.0.1:{port}"))
+            .await
+            .unwrap_err();
+
+        assert!(WorkOsError::<()>::RequestError(err).is_retryable())
+    }
+
+    #[tokio::test]
+    async fn it_considers_timeouts_retryable() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let err = reqwest::Client::builder()
+            .timeout(Duration::from_millis(50))
+            .build()
+            .unwrap()
+            .get(format!("http://127.0.0.1:{port}"))
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(WorkOsError::<()>::RequestError(err).is_retryable())
+    }
+
+    #[test]
+    fn it_considers_service_unavailable_errors_retryable() {
+        assert!(WorkOsError::<()>::ServiceUnavailable {
+            retry_after: Some(Duration::from_secs(30))
+        }
+        .is_retryable())
+    }
+
+    #[test]
+    fn it_does_not_consider_other_errors_retryable() {
+        assert!(!WorkOsError::Operation(()).is_retryable());
+        assert!(!WorkOsError::<()>::Unauthorized.is_retryable());
+        assert!(!WorkOsError::<()>::Forbidden.is_retryable());
+        assert!(!WorkOsError::<()>::Validation(ValidationError {
+            code: None,
+            message: None,
+            field_errors: Vec::new(),
+        })
+        .is_retryable());
+        assert!(!WorkOsError::<()>::UrlParseError(url::ParseError::EmptyHost).is_retryable());
+        assert!(!WorkOsError::<()>::Deserialize {
+            source: serde_json::from_str::<()>("{").unwrap_err(),
+            context: "()".to_string(),
+        }
+        .is_retryable())
+    }
+
+    /// Returns the chain of errors starting from `err`, following [`Error::source`].
+    fn source_chain<'a>(err: &'a (dyn Error + 'static)) -> Vec<&'a (dyn Error + 'static)> {
+        std::iter::successors(Some(err), |&err| err.source()).collect()
+    }
+
+    #[tokio::test]
+    async fn it_chains_the_sources_of_a_request_error() {
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let err = WorkOsError::<()>::from(
+            reqwest::get(format!("http://127.0.0.1:{port}"))
+                .await
+                .unwrap_err(),
+        );
+
+        let chain = source_chain(&err);
+
+        assert!(chain[1].is::<reqwest::Error>());
+        assert!(chain.iter().any(
+            |err| err.downcast_ref::<std::io::Error>().map(|err| err.kind())
+                == Some(std::io::ErrorKind::ConnectionRefused)
+        ))
+    }
+
+    #[test]
+    fn it_chains_the_source_of_a_deserialize_error() {
+        let err = WorkOsError::<()>::Deserialize {
+            source: serde_json::from_str::<()>("{").unwrap_err(),
+            context: "()".to_string(),
+        };
+
+        let chain = source_chain(&err);
+
+        assert_eq!(chain.len(), 2);
+        assert!(chain[1].is::<serde_json::Error>())
+    }
+
+    #[test]
+    fn it_chains_the_source_of_a_url_parse_error() {
+        let err = WorkOsError::<()>::from(url::ParseError::EmptyHost);
+
+        let chain = source_chain(&err);
+
+        assert_eq!(chain.len(), 2);
+        assert!(chain[1].is::<url::ParseError>())
+    }
+
+    #[test]
+    fn it_includes_the_operation_error_in_the_message() {
+        #[derive(Debug)]
+        enum ExampleError {
+            NotFound,
+        }
+
+        let err = WorkOsError::Operation(ExampleError::NotFound);
+
+        assert_eq!(err.to_string(), "operational error: NotFound")
+    }
+}
@@ -0,0 +1,141 @@
+use reqwest::{Request, Response};
+
+/// Observes the requests made by a [`WorkOs`](crate::WorkOs) client.
+///
+/// Observers are registered using
+/// [`WorkOsBuilder::observer`](crate::WorkOsBuilder::observer) and are called for
+/// every request sent to the WorkOS API, in the order they were registered. They can
+/// be used to record metrics or to modify outgoing requests, such as by adding
+/// headers.
+///
+/// # Examples
+///
+/// ```
+/// use reqwest::header::HeaderValue;
+/// use reqwest::{Request, Response};
+/// use workos::{ApiKey, RequestObserver, WorkOs};
+///
+/// struct RequestIdObserver;
+///
+/// impl RequestObserver for RequestIdObserver {
+///     fn on_request(&self, request: &mut Request) {
+///         request
+///             .headers_mut()
+///             .insert("X-Request-Id", HeaderValue::from_static("req_123"));
+///     }
+///
+///     fn on_response(&self, response: &Response) {
+///         println!("{} {}", response.url().path(), response.status());
+///     }
+/// }
+///
+/// let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+///     .observer(RequestIdObserver)
+///     .build();
+/// ```
+pub trait RequestObserver: Send + Sync {
+    /// Called before a request is sent.
+    ///
+    /// The request may be modified before it is sent.
+    fn on_request(&self, _request: &mut Request) {}
+
+    /// Called after a response is received, before it is handled.
+    ///
+    /// This is not called if the request failed without receiving a response.
+    fn on_response(&self, _response: &Response) {}
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use reqwest::header::HeaderValue;
+
+    use crate::organizations::{GetOrganization, OrganizationId};
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct RecordingObserver {
+        requests: Arc<Mutex<Vec<String>>>,
+        responses: Arc<Mutex<Vec<u16>>>,
+    }
+
+    impl RequestObserver for RecordingObserver {
+        fn on_request(&self, request: &mut Request) {
+            self.requests
+                .lock()
+                .unwrap()
+                .push(request.url().path().to_string());
+        }
+
+        fn on_response(&self, response: &Response) {
+            self.responses
+                .lock()
+                .unwrap()
+                .push(response.status().as_u16());
+        }
+    }
+
+    struct HeaderObserver;
+
+    impl RequestObserver for HeaderObserver {
+        fn on_request(&self, request: &mut Request) {
+            request
+                .headers_mut()
+                .insert("X-Request-Id", HeaderValue::from_static("req_123"));
+        }
+    }
+
+    #[tokio::test]
+    async fn it_calls_the_observer_with_the_request_and_response() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(404)
+            .create();
+
+        let observer = RecordingObserver::default();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .observer(observer.clone())
+            .build();
+
+        let _ = workos
+            .organizations()
+            .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .await;
+
+        assert_eq!(
+            *observer.requests.lock().unwrap(),
+            vec!["/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT".to_string()]
+        );
+        assert_eq!(*observer.responses.lock().unwrap(), vec![404]);
+    }
+
+    #[tokio::test]
+    async fn it_sends_the_request_as_modified_by_the_observer() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .match_header("X-Request-Id", "req_123")
+            .with_status(404)
+            .create();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .observer(HeaderObserver)
+            .build();
+
+        let _ = workos
+            .organizations()
+            .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .await;
+
+        mock.assert();
+    }
+}
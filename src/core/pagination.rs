@@ -0,0 +1,91 @@
+use std::future::Future;
+
+use futures_util::stream::{self, BoxStream, StreamExt, TryStreamExt};
+
+use crate::{PaginatedList, WorkOsResult};
+
+/// Returns a stream of the records across all pages of a paginated endpoint.
+///
+/// `fetch` is called with the cursor of the page to retrieve, starting with
+/// [`None`] for the first page, until a page is returned without an `after`
+/// cursor. The stream ends after yielding the first error.
+///
+/// Each call to `fetch` sends its request with the client's retry policy, so a page
+/// that fails with a retryable error is retried from the same cursor before an error
+/// is yielded.
+pub(crate) fn paginate<'a, T, E, F, Fut>(mut fetch: F) -> BoxStream<'a, WorkOsResult<T, E>>
+where
+    T: Send + 'a,
+    E: Send + 'a,
+    F: FnMut(Option<String>) -> Fut + Send + 'a,
+    Fut: Future<Output = WorkOsResult<PaginatedList<T>, E>> + Send + 'a,
+{
+    stream::try_unfold(Some(None), move |cursor: Option<Option<String>>| {
+        let page = cursor.map(&mut fetch);
+
+        async move {
+            match page {
+                Some(page) => page.await.map(|page| {
+                    let next_cursor = page.metadata.after.map(Some);
+
+                    Some((stream::iter(page.data.into_iter().map(Ok)), next_cursor))
+                }),
+                None => Ok(None),
+            }
+        }
+    })
+    .try_flatten()
+    .boxed()
+}
+
+#[cfg(test)]
+mod test {
+    use futures_util::TryStreamExt;
+
+    use crate::{ListMetadata, PaginatedList, WorkOsError};
+
+    use super::*;
+
+    fn page(data: Vec<u32>, after: Option<&str>) -> PaginatedList<u32> {
+        PaginatedList {
+            data,
+            metadata: ListMetadata {
+                before: None,
+                after: after.map(str::to_string),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn it_yields_the_records_from_every_page() {
+        let records = paginate(|cursor| async move {
+            match cursor.as_deref() {
+                None => Ok::<_, WorkOsError<()>>(page(vec![1, 2], Some("cursor_2"))),
+                Some("cursor_2") => Ok(page(vec![3], Some("cursor_3"))),
+                Some("cursor_3") => Ok(page(vec![4, 5], None)),
+                Some(cursor) => panic!("unexpected cursor {cursor}"),
+            }
+        })
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+        assert_eq!(records, vec![1, 2, 3, 4, 5])
+    }
+
+    #[tokio::test]
+    async fn it_stops_after_an_error() {
+        let results = paginate(|cursor| async move {
+            match cursor {
+                None => Ok(page(vec![1], Some("cursor_2"))),
+                Some(_) => Err(WorkOsError::Operation(())),
+            }
+        })
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[0], Ok(1)));
+        assert!(matches!(results[1], Err(WorkOsError::Operation(()))));
+    }
+}
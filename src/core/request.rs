@@ -0,0 +1,371 @@
+use async_trait::async_trait;
+use reqwest::{Client, Request, RequestBuilder, Response};
+
+use crate::{is_idempotent_method, is_retryable_status, retry_after, MaxResponseBytes, WorkOs};
+
+#[async_trait]
+pub trait RequestExt {
+    /// Sends the request to the WorkOS API, calling the
+    /// [`RequestObserver`](crate::RequestObserver)s registered on the client.
+    ///
+    /// If the client has a [`RetryConfig`](crate::RetryConfig), requests with an
+    /// idempotent method that fail with a retryable error are retried, and the observers
+    /// are called for each attempt.
+    ///
+    /// When the `tracing` feature is enabled each attempt is wrapped in a
+    /// `workos.request` span that records the method, path, status, and duration.
+    /// The query string is left out of the span as it may contain sensitive values,
+    /// such as authorization codes.
+    ///
+    /// When the `debug-params` feature is enabled a `workos.request.params` event is
+    /// also emitted at the `DEBUG` level with the query string and body of the request.
+    /// Values of sensitive parameters, such as client secrets, are redacted.
+    async fn send_instrumented(self, workos: &WorkOs) -> Result<Response, reqwest::Error>;
+}
+
+#[async_trait]
+impl RequestExt for RequestBuilder {
+    async fn send_instrumented(self, workos: &WorkOs) -> Result<Response, reqwest::Error> {
+        let (client, request) = self.build_split();
+        let request = request?;
+
+        let Some(retry_config) = workos
+            .retry_config()
+            .filter(|_| is_idempotent_method(request.method()))
+        else {
+            return send_observed(&client, request, workos).await;
+        };
+
+        let mut retry = 0;
+        loop {
+            let attempt = match request.try_clone() {
+                Some(attempt) if retry < retry_config.max_retries => attempt,
+                _ => return send_observed(&client, request, workos).await,
+            };
+
+            let retry_after = match send_observed(&client, attempt, workos).await {
+                Ok(response) if !is_retryable_status(response.status()) => return Ok(response),
+                Ok(response) => retry_after(&response),
+                Err(err) if !err.is_connect() && !err.is_timeout() => return Err(err),
+                Err(_) => None,
+            };
+
+            tokio::time::sleep(retry_config.delay_with_retry_after(retry, retry_after)).await;
+            retry += 1;
+        }
+    }
+}
+
+/// Sends a single request, calling the observers registered on the client.
+async fn send_observed(
+    client: &Client,
+    mut request: Request,
+    workos: &WorkOs,
+) -> Result<Response, reqwest::Error> {
+    for observer in workos.observers() {
+        observer.on_request(&mut request);
+    }
+
+    let mut response = execute(client, request).await?;
+
+    for observer in workos.observers() {
+        observer.on_response(&response);
+    }
+
+    if let Some(limit) = workos.max_response_bytes() {
+        response.extensions_mut().insert(MaxResponseBytes(limit));
+    }
+
+    Ok(response)
+}
+
+#[cfg(not(feature = "tracing"))]
+async fn execute(client: &Client, request: Request) -> Result<Response, reqwest::Error> {
+    client.execute(request).await
+}
+
+#[cfg(feature = "tracing")]
+async fn execute(client: &Client, request: Request) -> Result<Response, reqwest::Error> {
+    use std::time::Instant;
+
+    use tracing::field::Empty;
+    use tracing::Instrument;
+
+    let span = tracing::info_span!(
+        "workos.request",
+        method = %request.method(),
+        path = request.url().path(),
+        status = Empty,
+        duration_ms = Empty,
+    );
+
+    #[cfg(feature = "debug-params")]
+    span.in_scope(|| log_params(&request));
+
+    let started_at = Instant::now();
+    let result = client.execute(request).instrument(span.clone()).await;
+
+    span.record("duration_ms", started_at.elapsed().as_millis() as u64);
+    if let Ok(response) = &result {
+        span.record("status", response.status().as_u16());
+    }
+
+    result
+}
+
+/// The names of parameters whose values are redacted when logging the parameters of a request.
+#[cfg(feature = "debug-params")]
+const SENSITIVE_PARAMS: &[&str] = &[
+    "access_token",
+    "client_secret",
+    "code",
+    "password",
+    "refresh_token",
+];
+
+#[cfg(feature = "debug-params")]
+const REDACTED: &str = "[REDACTED]";
+
+#[cfg(feature = "debug-params")]
+fn log_params(request: &Request) {
+    let query = request.url().query().map(redact_form);
+    let body = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .map(|bytes| match serde_json::from_slice(bytes) {
+            Ok(mut value) => {
+                redact_json(&mut value);
+                value.to_string()
+            }
+            Err(_) => redact_form(&String::from_utf8_lossy(bytes)),
+        });
+
+    tracing::debug!(
+        query = query.as_deref(),
+        body = body.as_deref(),
+        "workos.request.params"
+    );
+}
+
+/// Redacts the values of sensitive parameters in a URL-encoded string.
+#[cfg(feature = "debug-params")]
+fn redact_form(encoded: &str) -> String {
+    let pairs = url::form_urlencoded::parse(encoded.as_bytes()).map(|(key, value)| {
+        if SENSITIVE_PARAMS.contains(&key.as_ref()) {
+            (key, REDACTED.into())
+        } else {
+            (key, value)
+        }
+    });
+
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(pairs)
+        .finish()
+}
+
+/// Redacts the values of sensitive fields in a JSON value.
+#[cfg(feature = "debug-params")]
+fn redact_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if SENSITIVE_PARAMS.contains(&key.as_str()) {
+                    *value = REDACTED.into();
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod test {
+    use std::collections::HashMap;
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Dispatch, Event, Metadata, Subscriber};
+
+    use crate::organizations::{GetOrganization, OrganizationId};
+    use crate::{ApiKey, WorkOs};
+
+    /// A span captured by [`RecordingSubscriber`].
+    #[derive(Debug, Default)]
+    struct RecordedSpan {
+        name: &'static str,
+        fields: HashMap<&'static str, String>,
+    }
+
+    impl Visit for RecordedSpan {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.fields.insert(field.name(), format!("{value:?}"));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.fields.insert(field.name(), value.to_string());
+        }
+    }
+
+    /// A subscriber that records every span that is created and every event that is emitted.
+    #[derive(Clone, Default)]
+    struct RecordingSubscriber {
+        spans: Arc<Mutex<Vec<RecordedSpan>>>,
+        events: Arc<Mutex<Vec<RecordedSpan>>>,
+    }
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+            let mut span = RecordedSpan {
+                name: attributes.metadata().name(),
+                ..Default::default()
+            };
+            attributes.record(&mut span);
+
+            let mut spans = self.spans.lock().unwrap();
+            spans.push(span);
+
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, id: &Id, values: &Record<'_>) {
+            let mut spans = self.spans.lock().unwrap();
+            values.record(&mut spans[id.into_u64() as usize - 1]);
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut recorded = RecordedSpan {
+                name: event.metadata().name(),
+                ..Default::default()
+            };
+            event.record(&mut recorded);
+
+            self.events.lock().unwrap().push(recorded);
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[tokio::test]
+    async fn it_emits_a_span_for_an_operation() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(404)
+            .create();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let subscriber = RecordingSubscriber::default();
+        let _guard = tracing::dispatcher::set_default(&Dispatch::new(subscriber.clone()));
+
+        let _ = workos
+            .organizations()
+            .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .await;
+
+        let spans = subscriber.spans.lock().unwrap();
+        let span = spans
+            .iter()
+            .find(|span| span.name == "workos.request")
+            .expect("no span was emitted");
+
+        assert_eq!(span.fields["method"], "GET");
+        assert_eq!(
+            span.fields["path"],
+            "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT"
+        );
+        assert_eq!(span.fields["status"], "404");
+        assert!(span.fields.contains_key("duration_ms"));
+        assert!(!span
+            .fields
+            .values()
+            .any(|value| value.contains("sk_example_123456789")));
+    }
+
+    #[cfg(feature = "debug-params")]
+    #[tokio::test]
+    async fn it_logs_the_redacted_params_of_a_request() {
+        use crate::user_management::{AuthenticateWithCode, AuthenticateWithCodeParams};
+        use crate::{AuthorizationCode, ClientId, ClientSecret};
+
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(500)
+            .create();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let subscriber = RecordingSubscriber::default();
+        let _guard = tracing::dispatcher::set_default(&Dispatch::new(subscriber.clone()));
+
+        let _ = workos
+            .user_management()
+            .authenticate_with_code(&AuthenticateWithCodeParams {
+                client_id: Some(&ClientId::from("client_1234")),
+                client_secret: Some(&ClientSecret::from("very_secret")),
+                grant_type: "authorization_code".into(),
+                code: &AuthorizationCode::from("abc123"),
+                ip_address: "1.2.3.4".into(),
+                user_agent: "Mozilla/5.0".into(),
+            })
+            .await;
+
+        let events = subscriber.events.lock().unwrap();
+        let event = events
+            .iter()
+            .find(|event| {
+                event.fields.get("message").map(String::as_str) == Some("workos.request.params")
+            })
+            .expect("no params event was emitted");
+
+        assert_eq!(
+            event.fields["body"],
+            "client_id=client_1234&client_secret=%5BREDACTED%5D&grant_type=authorization_code&code=%5BREDACTED%5D&ip_address=1.2.3.4&user_agent=Mozilla%2F5.0"
+        );
+        assert!(!events
+            .iter()
+            .flat_map(|event| event.fields.values())
+            .any(|value| value.contains("very_secret") || value.contains("sk_example_123456789")));
+    }
+
+    #[cfg(feature = "debug-params")]
+    #[test]
+    fn it_redacts_sensitive_json_fields() {
+        let mut value = serde_json::json!({
+            "email": "marcelina@example.com",
+            "password": "hunter2",
+            "nested": [{ "refresh_token": "token" }]
+        });
+
+        super::redact_json(&mut value);
+
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "email": "marcelina@example.com",
+                "password": "[REDACTED]",
+                "nested": [{ "refresh_token": "[REDACTED]" }]
+            })
+        );
+    }
+}
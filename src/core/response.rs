@@ -1,27 +1,63 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::header::RETRY_AFTER;
 use reqwest::{Response, StatusCode};
+use serde::de::DeserializeOwned;
 
-use crate::{WorkOsError, WorkOsResult};
+use crate::{ValidationError, WorkOsError, WorkOsResult};
 
+#[async_trait]
 pub trait ResponseExt
 where
     Self: Sized,
 {
-    /// Handles an unauthorized error from the WorkOS API by converting it into a
-    /// [`WorkOsError::Unauthorized`] response.
+    /// Handles an unauthorized or forbidden error from the WorkOS API by converting it
+    /// into a [`WorkOsError::Unauthorized`] or [`WorkOsError::Forbidden`] response.
     fn handle_unauthorized_error<E>(self) -> WorkOsResult<Self, E>;
 
+    /// Handles a service unavailable error from the WorkOS API by converting it into a
+    /// [`WorkOsError::ServiceUnavailable`] response.
+    fn handle_service_unavailable_error<E>(self) -> WorkOsResult<Self, E>;
+
     /// Handles a generic error from the WorkOS API by converting it into a
     /// [`WorkOsError::RequestError`] response.
     fn handle_generic_error<E>(self) -> WorkOsResult<Self, E>;
 
-    /// Handles an unauthorized or generic error from the WorkOS API.
-    fn handle_unauthorized_or_generic_error<E>(self) -> WorkOsResult<Self, E>;
+    /// Handles a validation error from the WorkOS API by converting it into a
+    /// [`WorkOsError::Validation`] response.
+    async fn handle_validation_error<E>(self) -> WorkOsResult<Self, E>;
+
+    /// Handles an unauthorized, forbidden, validation, service unavailable, or generic
+    /// error from the WorkOS API.
+    async fn handle_unauthorized_or_generic_error<E>(self) -> WorkOsResult<Self, E>;
+
+    /// Deserializes the response body as JSON, converting a failure into a
+    /// [`WorkOsError::Deserialize`] response that preserves the underlying error.
+    ///
+    /// An empty body is treated as `null`, so types such as `()` and [`Option`] can be
+    /// parsed from it. For other types a [`WorkOsError::EmptyBody`] response is returned.
+    async fn parse_json<T, E>(self) -> WorkOsResult<T, E>
+    where
+        T: DeserializeOwned;
 }
 
+#[async_trait]
 impl ResponseExt for Response {
     fn handle_unauthorized_error<E>(self) -> WorkOsResult<Self, E> {
-        if self.status() == StatusCode::UNAUTHORIZED {
-            Err(WorkOsError::Unauthorized)
+        match self.status() {
+            StatusCode::UNAUTHORIZED => Err(WorkOsError::Unauthorized),
+            StatusCode::FORBIDDEN => Err(WorkOsError::Forbidden),
+            _ => Ok(self),
+        }
+    }
+
+    fn handle_service_unavailable_error<E>(self) -> WorkOsResult<Self, E> {
+        if self.status() == StatusCode::SERVICE_UNAVAILABLE {
+            Err(WorkOsError::ServiceUnavailable {
+                retry_after: retry_after(&self),
+            })
         } else {
             Ok(self)
         }
@@ -34,7 +70,379 @@ impl ResponseExt for Response {
         }
     }
 
-    fn handle_unauthorized_or_generic_error<E>(self) -> WorkOsResult<Self, E> {
-        self.handle_unauthorized_error()?.handle_generic_error()
+    async fn handle_validation_error<E>(self) -> WorkOsResult<Self, E> {
+        if self.status() == StatusCode::UNPROCESSABLE_ENTITY {
+            Err(WorkOsError::Validation(
+                self.parse_json::<ValidationError, E>().await?,
+            ))
+        } else {
+            Ok(self)
+        }
+    }
+
+    async fn handle_unauthorized_or_generic_error<E>(self) -> WorkOsResult<Self, E> {
+        let response = self.handle_unauthorized_error()?;
+
+        response
+            .handle_validation_error()
+            .await?
+            .handle_service_unavailable_error()?
+            .handle_generic_error()
+    }
+
+    async fn parse_json<T, E>(self) -> WorkOsResult<T, E>
+    where
+        T: DeserializeOwned,
+    {
+        let body = read_body(self).await?;
+
+        if body.trim_ascii().is_empty() {
+            return serde_json::from_value(serde_json::Value::Null).map_err(|_| {
+                WorkOsError::EmptyBody {
+                    context: std::any::type_name::<T>().to_string(),
+                }
+            });
+        }
+
+        serde_json::from_slice(&body).map_err(|source| WorkOsError::Deserialize {
+            source,
+            context: std::any::type_name::<T>().to_string(),
+        })
+    }
+}
+
+/// The maximum number of bytes to read from a response body.
+///
+/// This is stored in the extensions of the response when the client has a limit set.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MaxResponseBytes(pub(crate) usize);
+
+/// Reads the response body, stopping once it exceeds the [`MaxResponseBytes`] of the
+/// response, if it has one.
+async fn read_body<E>(mut response: Response) -> WorkOsResult<Vec<u8>, E> {
+    let Some(&MaxResponseBytes(limit)) = response.extensions().get::<MaxResponseBytes>() else {
+        return Ok(response.bytes().await?.to_vec());
+    };
+
+    if response
+        .content_length()
+        .is_some_and(|length| length > limit as u64)
+    {
+        return Err(WorkOsError::ResponseTooLarge { limit });
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() + chunk.len() > limit {
+            return Err(WorkOsError::ResponseTooLarge { limit });
+        }
+
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(body)
+}
+
+/// Returns the delay from the `Retry-After` header of the response, if it has one.
+pub(crate) fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_retry_after)
+}
+
+/// Parses a `Retry-After` header value, which is either a number of seconds or an
+/// HTTP date.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    if let Ok(seconds) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let retry_at = DateTime::parse_from_rfc2822(value.trim()).ok()?;
+
+    Some(
+        retry_at
+            .with_timezone(&Utc)
+            .signed_duration_since(Utc::now())
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use mockito::{self};
+    use serde::Deserialize;
+
+    use crate::FieldError;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn it_returns_a_validation_error_with_the_field_errors() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/example")
+            .with_status(422)
+            .with_body(
+                serde_json::json!({
+                    "code": "invalid_request_parameters",
+                    "message": "Validation failed",
+                    "errors": [
+                        {
+                            "field": "email",
+                            "code": "email_required"
+                        },
+                        {
+                            "field": "password",
+                            "code": "password_too_short"
+                        }
+                    ]
+                })
+                .to_string(),
+            )
+            .create();
+
+        let response = reqwest::Client::new()
+            .post(format!("{}/example", server.url()))
+            .send()
+            .await
+            .unwrap();
+
+        let result = response.handle_unauthorized_or_generic_error::<()>().await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Validation(ValidationError { code, message, field_errors }))
+                if code.as_deref() == Some("invalid_request_parameters")
+                    && message.as_deref() == Some("Validation failed")
+                    && field_errors == vec![
+                        FieldError {
+                            field: "email".to_string(),
+                            code: "email_required".to_string(),
+                        },
+                        FieldError {
+                            field: "password".to_string(),
+                            code: "password_too_short".to_string(),
+                        },
+                    ]
+        )
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Example {
+        #[allow(dead_code)]
+        id: String,
+    }
+
+    #[tokio::test]
+    async fn it_returns_a_deserialize_error_when_the_body_is_malformed() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/example")
+            .with_status(200)
+            .with_body(r#"{"id": 123}"#)
+            .create();
+
+        let response = reqwest::get(format!("{}/example", server.url()))
+            .await
+            .unwrap();
+
+        let result = response.parse_json::<Example, ()>().await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Deserialize { source, context })
+                if source.line() == 1 && context.ends_with("Example")
+        )
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_empty_body_error_when_data_is_expected() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/example")
+            .with_status(200)
+            .with_body("")
+            .create();
+
+        let response = reqwest::get(format!("{}/example", server.url()))
+            .await
+            .unwrap();
+
+        let result = response.parse_json::<Example, ()>().await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::EmptyBody { context }) if context.ends_with("Example")
+        )
+    }
+
+    #[tokio::test]
+    async fn it_parses_an_empty_body_when_no_data_is_expected() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/example").with_status(204).create();
+
+        let response = reqwest::get(format!("{}/example", server.url()))
+            .await
+            .unwrap();
+
+        assert_matches!(response.parse_json::<(), ()>().await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn it_returns_a_service_unavailable_error_with_the_retry_after_delay() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/example")
+            .with_status(503)
+            .with_header("Retry-After", "120")
+            .create();
+
+        let response = reqwest::get(format!("{}/example", server.url()))
+            .await
+            .unwrap();
+
+        let result = response.handle_unauthorized_or_generic_error::<()>().await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::ServiceUnavailable { retry_after: Some(retry_after) })
+                if retry_after == Duration::from_secs(120)
+        )
+    }
+
+    #[tokio::test]
+    async fn it_returns_a_service_unavailable_error_without_a_retry_after_header() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/example").with_status(503).create();
+
+        let response = reqwest::get(format!("{}/example", server.url()))
+            .await
+            .unwrap();
+
+        let result = response.handle_unauthorized_or_generic_error::<()>().await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::ServiceUnavailable { retry_after: None })
+        )
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_unauthorized_error() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/example").with_status(401).create();
+
+        let response = reqwest::get(format!("{}/example", server.url()))
+            .await
+            .unwrap();
+
+        let result = response.handle_unauthorized_or_generic_error::<()>().await;
+
+        assert_matches!(result, Err(WorkOsError::Unauthorized))
+    }
+
+    #[tokio::test]
+    async fn it_returns_a_forbidden_error() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/example")
+            .with_status(403)
+            .with_body(r#"{"message": "Forbidden"}"#)
+            .create();
+
+        let response = reqwest::get(format!("{}/example", server.url()))
+            .await
+            .unwrap();
+
+        let result = response.handle_unauthorized_or_generic_error::<()>().await;
+
+        assert_matches!(result, Err(WorkOsError::Forbidden))
+    }
+
+    #[tokio::test]
+    async fn it_returns_a_request_error_for_other_server_errors() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/example").with_status(500).create();
+
+        let response = reqwest::get(format!("{}/example", server.url()))
+            .await
+            .unwrap();
+
+        let result = response.handle_unauthorized_or_generic_error::<()>().await;
+
+        assert_matches!(result, Err(WorkOsError::RequestError(_)))
+    }
+
+    #[test]
+    fn it_parses_a_retry_after_http_date() {
+        let retry_at = (Utc::now() + chrono::Duration::seconds(300)).to_rfc2822();
+
+        let retry_after = parse_retry_after(&retry_at).unwrap();
+
+        assert!(retry_after > Duration::from_secs(290) && retry_after <= Duration::from_secs(300))
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_the_response_exceeds_the_max_response_bytes() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/example")
+            .with_status(200)
+            .with_body(r#"{"message": "This body is too large"}"#)
+            .create();
+
+        let mut response = reqwest::get(format!("{}/example", server.url()))
+            .await
+            .unwrap();
+        response.extensions_mut().insert(MaxResponseBytes(16));
+
+        let result = response.parse_json::<serde_json::Value, ()>().await;
+
+        assert_matches!(result, Err(WorkOsError::ResponseTooLarge { limit: 16 }))
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_a_chunked_response_exceeds_the_max_response_bytes() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/example")
+            .with_status(200)
+            .with_chunked_body(|writer| {
+                writer.write_all(br#"{"message": "#)?;
+                writer.write_all(br#""This body is too large"}"#)
+            })
+            .create();
+
+        let mut response = reqwest::get(format!("{}/example", server.url()))
+            .await
+            .unwrap();
+        response.extensions_mut().insert(MaxResponseBytes(16));
+
+        let result = response.parse_json::<serde_json::Value, ()>().await;
+
+        assert_matches!(result, Err(WorkOsError::ResponseTooLarge { limit: 16 }))
+    }
+
+    #[tokio::test]
+    async fn it_parses_a_response_within_the_max_response_bytes() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/example")
+            .with_status(200)
+            .with_body(r#"{"message": "OK"}"#)
+            .create();
+
+        let mut response = reqwest::get(format!("{}/example", server.url()))
+            .await
+            .unwrap();
+        response.extensions_mut().insert(MaxResponseBytes(1024));
+
+        let result = response.parse_json::<serde_json::Value, ()>().await;
+
+        assert_matches!(result, Ok(value) if value["message"] == "OK")
     }
 }
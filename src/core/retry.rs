@@ -0,0 +1,334 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+
+/// The configuration for retrying requests, set using
+/// [`WorkOsBuilder::retry`](crate::WorkOsBuilder::retry).
+///
+/// Requests that are rate limited, time out, fail to connect, or receive a server
+/// error (5xx) are retried with an exponential backoff. The delay before each retry
+/// doubles from [`base_delay`](Self::base_delay) up to [`max_delay`](Self::max_delay).
+/// When the response has a `Retry-After` header its delay is used instead, capped at
+/// [`max_delay`](Self::max_delay).
+///
+/// Only requests with an idempotent method (`GET`, `HEAD`, `PUT`, and `DELETE`) are
+/// retried, so that a `POST` that may have been processed is never sent twice.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use workos::{ApiKey, RetryConfig, WorkOs};
+///
+/// let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+///     .retry(RetryConfig {
+///         max_retries: 5,
+///         max_delay: Duration::from_secs(10),
+///         ..Default::default()
+///     })
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// The maximum number of times a request is retried.
+    pub max_retries: u32,
+
+    /// The delay before the first retry.
+    pub base_delay: Duration,
+
+    /// The maximum delay before any retry.
+    pub max_delay: Duration,
+
+    /// Whether to apply full jitter to the delay, choosing a random delay between zero
+    /// and the backoff delay.
+    ///
+    /// This avoids many clients retrying at the same time after a shared failure.
+    pub jitter: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Returns the delay before the given retry, starting from `0` for the first retry.
+    pub(crate) fn delay(&self, retry: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay);
+
+        if self.jitter {
+            delay.mul_f64(random_fraction())
+        } else {
+            delay
+        }
+    }
+
+    /// Returns the delay before the given retry, using the `Retry-After` delay of the
+    /// response, capped at the [`max_delay`](Self::max_delay), when it has one.
+    pub(crate) fn delay_with_retry_after(
+        &self,
+        retry: u32,
+        retry_after: Option<Duration>,
+    ) -> Duration {
+        match retry_after {
+            Some(retry_after) => retry_after.min(self.max_delay),
+            None => self.delay(retry),
+        }
+    }
+}
+
+/// Returns whether a request with the given method can be safely retried.
+pub(crate) fn is_idempotent_method(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE
+    )
+}
+
+/// Returns whether a response with the given status should be retried.
+pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Returns a random number in the range `[0, 1)`.
+///
+/// This does not need to be cryptographically secure, so the randomly seeded hasher
+/// from the standard library is used rather than pulling in a random number generator.
+fn random_fraction() -> f64 {
+    let bits = RandomState::new().build_hasher().finish() >> 11;
+
+    bits as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+
+    use std::collections::HashSet;
+    use std::time::Instant;
+
+    use crate::organizations::{
+        CreateOrganization, CreateOrganizationParams, GetOrganization, OrganizationId,
+    };
+    use crate::{ApiKey, WorkOs, WorkOsError};
+
+    use super::*;
+
+    fn no_jitter() -> RetryConfig {
+        RetryConfig {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            jitter: false,
+        }
+    }
+
+    #[test]
+    fn it_doubles_the_delay_up_to_the_max_delay_without_jitter() {
+        let config = RetryConfig {
+            max_retries: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+            jitter: false,
+        };
+
+        let delays = (0..6).map(|retry| config.delay(retry)).collect::<Vec<_>>();
+
+        assert_eq!(
+            delays,
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+                Duration::from_millis(400),
+                Duration::from_millis(800),
+                Duration::from_millis(1000),
+                Duration::from_millis(1000),
+            ]
+        )
+    }
+
+    #[test]
+    fn it_does_not_overflow_for_large_retry_counts() {
+        let config = RetryConfig {
+            jitter: false,
+            ..Default::default()
+        };
+
+        assert_eq!(config.delay(u32::MAX), config.max_delay)
+    }
+
+    #[test]
+    fn it_keeps_jittered_delays_within_the_backoff_delay() {
+        let config = RetryConfig {
+            max_retries: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+            jitter: true,
+        };
+
+        for _ in 0..20 {
+            assert!(config.delay(0) <= Duration::from_millis(100));
+            assert!(config.delay(2) <= Duration::from_millis(400));
+            assert!(config.delay(5) <= Duration::from_millis(1000));
+        }
+    }
+
+    #[tokio::test]
+    async fn it_retries_a_retryable_response_up_to_the_max_retries() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(500)
+            .expect(3)
+            .create();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .retry(no_jitter())
+            .build();
+
+        let result = workos
+            .organizations()
+            .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .await;
+
+        mock.assert();
+        assert_matches!(
+            result,
+            Err(WorkOsError::RequestError(err))
+                if err.status() == Some(StatusCode::INTERNAL_SERVER_ERROR)
+        )
+    }
+
+    #[tokio::test]
+    async fn it_does_not_retry_a_client_error() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(404)
+            .expect(1)
+            .create();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .retry(no_jitter())
+            .build();
+
+        let _ = workos
+            .organizations()
+            .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .await;
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn it_does_not_retry_without_a_retry_config() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(500)
+            .expect(1)
+            .create();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _ = workos
+            .organizations()
+            .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .await;
+
+        mock.assert();
+    }
+
+    #[test]
+    fn it_caps_the_retry_after_delay_at_the_max_delay() {
+        let config = no_jitter();
+
+        assert_eq!(
+            config.delay_with_retry_after(0, Some(Duration::from_millis(2))),
+            Duration::from_millis(2)
+        );
+        assert_eq!(
+            config.delay_with_retry_after(0, Some(Duration::from_secs(3600))),
+            config.max_delay
+        );
+        assert_eq!(config.delay_with_retry_after(1, None), config.delay(1));
+    }
+
+    #[tokio::test]
+    async fn it_waits_for_the_retry_after_delay_before_retrying() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(429)
+            .with_header("Retry-After", "1")
+            .expect(2)
+            .create();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .retry(RetryConfig {
+                max_retries: 1,
+                max_delay: Duration::from_millis(200),
+                ..no_jitter()
+            })
+            .build();
+
+        let started_at = Instant::now();
+        let _ = workos
+            .organizations()
+            .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .await;
+
+        mock.assert();
+        let elapsed = started_at.elapsed();
+        assert!(elapsed >= Duration::from_millis(200), "{elapsed:?}");
+        assert!(elapsed < Duration::from_secs(1), "{elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn it_does_not_retry_a_non_idempotent_request() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/organizations")
+            .with_status(500)
+            .expect(1)
+            .create();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .retry(no_jitter())
+            .build();
+
+        let _ = workos
+            .organizations()
+            .create_organization(&CreateOrganizationParams {
+                name: "Foo Corp",
+                allow_profiles_outside_organization: None,
+                domains: HashSet::from(["foo-corp.com"]),
+            })
+            .await;
+
+        mock.assert();
+    }
+}
@@ -0,0 +1,179 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use thiserror::Error;
+
+/// An error returned from [`from_str_strict`].
+#[derive(Debug, Error)]
+pub enum StrictDeserializeError {
+    /// The JSON could not be deserialized.
+    #[error("failed to deserialize JSON")]
+    Json(#[from] serde_json::Error),
+
+    /// The JSON contained a field that is not modeled by the SDK.
+    #[error("unknown field `{path}`")]
+    UnknownField {
+        /// The path to the field, such as `data[0].name`.
+        path: String,
+    },
+}
+
+/// Deserializes a WorkOS API payload, returning an error if it contains any fields
+/// that are not modeled by the SDK.
+///
+/// The SDK ignores unknown fields by default so that it keeps working when the WorkOS
+/// API adds new ones. This can be used, for example in CI, to detect when the API has
+/// changed and the SDK needs to be updated to support it.
+///
+/// Unknown fields are detected by comparing the payload against the serialized form
+/// of the deserialized value, so fields that are `null` in the payload are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use workos::organizations::Organization;
+/// use workos::{from_str_strict, StrictDeserializeError};
+///
+/// let result = from_str_strict::<Organization>(
+///     r#"{
+///         "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+///         "object": "organization",
+///         "name": "Foo Corp",
+///         "domains": [],
+///         "created_at": "2021-06-25T19:07:33.155Z",
+///         "updated_at": "2021-06-25T19:07:33.155Z",
+///         "favorite_color": "blue"
+///     }"#,
+/// );
+///
+/// assert!(matches!(
+///     result,
+///     Err(StrictDeserializeError::UnknownField { path }) if path == "favorite_color"
+/// ));
+/// ```
+pub fn from_str_strict<T>(json: &str) -> Result<T, StrictDeserializeError>
+where
+    T: DeserializeOwned + Serialize,
+{
+    let input: Value = serde_json::from_str(json)?;
+    let value = T::deserialize(&input)?;
+    let output = serde_json::to_value(&value)?;
+
+    match find_unknown_field(&input, &output, "") {
+        Some(path) => Err(StrictDeserializeError::UnknownField { path }),
+        None => Ok(value),
+    }
+}
+
+/// Returns the path to the first non-null field in `input` that is missing from `output`.
+fn find_unknown_field(input: &Value, output: &Value, path: &str) -> Option<String> {
+    match (input, output) {
+        (Value::Object(input), Value::Object(output)) => input.iter().find_map(|(key, input)| {
+            let path = if path.is_empty() {
+                key.to_string()
+            } else {
+                format!("{path}.{key}")
+            };
+
+            match output.get(key) {
+                Some(output) => find_unknown_field(input, output, &path),
+                None if input.is_null() => None,
+                None => Some(path),
+            }
+        }),
+        (Value::Array(input), Value::Array(output)) => input
+            .iter()
+            .zip(output)
+            .enumerate()
+            .find_map(|(index, (input, output))| {
+                find_unknown_field(input, output, &format!("{path}[{index}]"))
+            }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::organizations::Organization;
+
+    use super::*;
+
+    fn organization_json(extra: Value) -> String {
+        let mut organization = json!({
+            "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+            "object": "organization",
+            "name": "Foo Corp",
+            "allow_profiles_outside_organization": false,
+            "domains": [
+                {
+                    "domain": "foo-corp.com",
+                    "id": "org_domain_01EHZNVPK2QXHMVWCEDQEKY69A"
+                }
+            ],
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        });
+        organization
+            .as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+
+        organization.to_string()
+    }
+
+    #[test]
+    fn it_deserializes_a_payload_without_unknown_fields() {
+        let organization = from_str_strict::<Organization>(&organization_json(json!({})));
+
+        assert_matches!(organization, Ok(Organization { name, .. }) if name == "Foo Corp")
+    }
+
+    #[test]
+    fn it_returns_an_error_for_an_unknown_field() {
+        let json = organization_json(json!({ "favorite_color": "blue" }));
+
+        assert!(serde_json::from_str::<Organization>(&json).is_ok());
+        assert_matches!(
+            from_str_strict::<Organization>(&json),
+            Err(StrictDeserializeError::UnknownField { path }) if path == "favorite_color"
+        )
+    }
+
+    #[test]
+    fn it_returns_the_path_to_a_nested_unknown_field() {
+        let json = json!({
+            "data": [
+                {
+                    "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                    "name": "Foo Corp",
+                    "domains": [],
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z",
+                    "favorite_color": "blue"
+                }
+            ]
+        })
+        .to_string();
+
+        #[derive(Debug, Deserialize, Serialize)]
+        struct List {
+            data: Vec<Organization>,
+        }
+
+        assert_matches!(
+            from_str_strict::<List>(&json),
+            Err(StrictDeserializeError::UnknownField { path }) if path == "data[0].favorite_color"
+        )
+    }
+
+    #[test]
+    fn it_ignores_unknown_fields_that_are_null() {
+        let json = organization_json(json!({ "favorite_color": null }));
+
+        assert!(from_str_strict::<Organization>(&json).is_ok())
+    }
+}
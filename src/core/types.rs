@@ -1,17 +1,25 @@
 mod api_key;
 mod authorization_code;
 mod client_id;
+mod client_secret;
+mod encoded_state;
 mod paginated_list;
 mod pagination_params;
 mod raw_attributes;
+#[cfg(feature = "crypto")]
+mod signed_state;
 mod timestamps;
 mod url_encodable_vec;
 
 pub use api_key::*;
 pub use authorization_code::*;
 pub use client_id::*;
+pub use client_secret::*;
+pub use encoded_state::*;
 pub use paginated_list::*;
 pub use pagination_params::*;
 pub use raw_attributes::*;
+#[cfg(feature = "crypto")]
+pub use signed_state::*;
 pub use timestamps::*;
 pub(crate) use url_encodable_vec::*;
@@ -1,9 +1,74 @@
+use std::env::{self, VarError};
 use std::fmt::Display;
 
+/// The mode of the environment an [`ApiKey`] belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyMode {
+    /// A key for a production environment, prefixed with `sk_live_`.
+    Live,
+
+    /// A key for a staging or sandbox environment, prefixed with `sk_test_`.
+    Test,
+
+    /// A key without a recognized prefix.
+    Unknown,
+}
+
 /// An API key to authenticate with the WorkOS API.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ApiKey(String);
 
+impl ApiKey {
+    /// The name of the environment variable the API key is read from by [`ApiKey::from_env`].
+    pub const ENV_VAR: &'static str = "WORKOS_API_KEY";
+
+    /// Reads the API key from the `WORKOS_API_KEY` environment variable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use workos::ApiKey;
+    ///
+    /// # fn run() -> Result<(), std::env::VarError> {
+    /// let api_key = ApiKey::from_env()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<Self, VarError> {
+        Self::from_env_var(Self::ENV_VAR)
+    }
+
+    /// Reads the API key from the environment variable with the given name.
+    pub fn from_env_var(name: &str) -> Result<Self, VarError> {
+        env::var(name).map(Self)
+    }
+
+    /// Returns the mode of the environment the API key belongs to, inferred from the
+    /// prefix of the key.
+    ///
+    /// This can be used to check that an application is using the expected
+    /// environment, such as to avoid running tests against a production environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use workos::{ApiKey, KeyMode};
+    ///
+    /// let api_key = ApiKey::from("sk_test_123456789");
+    ///
+    /// assert_eq!(api_key.mode(), KeyMode::Test);
+    /// ```
+    pub fn mode(&self) -> KeyMode {
+        if self.0.starts_with("sk_live_") {
+            KeyMode::Live
+        } else if self.0.starts_with("sk_test_") {
+            KeyMode::Test
+        } else {
+            KeyMode::Unknown
+        }
+    }
+}
+
 impl Display for ApiKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -21,3 +86,68 @@ impl From<&str> for ApiKey {
         Self(value.to_string())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::env::{self, VarError};
+
+    use super::{ApiKey, KeyMode};
+
+    #[test]
+    fn it_reads_the_api_key_from_the_environment() {
+        env::set_var(ApiKey::ENV_VAR, "sk_example_123456789");
+
+        assert_eq!(ApiKey::from_env(), Ok(ApiKey::from("sk_example_123456789")))
+    }
+
+    #[test]
+    fn it_reads_the_api_key_from_a_custom_environment_variable() {
+        env::set_var("WORKOS_API_KEY_FROM_ENV_VAR_TEST", "sk_example_987654321");
+
+        assert_eq!(
+            ApiKey::from_env_var("WORKOS_API_KEY_FROM_ENV_VAR_TEST"),
+            Ok(ApiKey::from("sk_example_987654321"))
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_when_the_environment_variable_is_not_set() {
+        assert_eq!(
+            ApiKey::from_env_var("WORKOS_API_KEY_MISSING_TEST"),
+            Err(VarError::NotPresent)
+        )
+    }
+
+    #[test]
+    fn it_infers_the_mode_from_the_key_prefix() {
+        assert_eq!(ApiKey::from("sk_live_123456789").mode(), KeyMode::Live);
+        assert_eq!(ApiKey::from("sk_test_123456789").mode(), KeyMode::Test);
+        assert_eq!(
+            ApiKey::from("sk_example_123456789").mode(),
+            KeyMode::Unknown
+        );
+    }
+
+    #[test]
+    fn it_returns_the_unknown_mode_for_unrecognized_prefixes() {
+        for key in [
+            "",
+            "sk_live",
+            "sk_test",
+            "SK_LIVE_123456789",
+            " sk_live_123456789",
+            "pk_live_123456789",
+            "sk_prod_123456789",
+            "live_123456789",
+        ] {
+            assert_eq!(ApiKey::from(key).mode(), KeyMode::Unknown, "{key:?}");
+        }
+    }
+
+    #[test]
+    fn it_only_matches_the_mode_at_the_start_of_the_key() {
+        assert_eq!(ApiKey::from("sk_test_sk_live_123").mode(), KeyMode::Test);
+        assert_eq!(ApiKey::from("sk_live_sk_test_123").mode(), KeyMode::Live);
+        assert_eq!(ApiKey::from("key_sk_live_123").mode(), KeyMode::Unknown);
+    }
+}
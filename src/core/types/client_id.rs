@@ -1,9 +1,11 @@
+use std::convert::Infallible;
 use std::fmt::Display;
+use std::str::FromStr;
 
 /// A client ID used to initiate SSO.
 ///
 /// Each environment will have its own client ID.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ClientId(String);
 
 impl Display for ClientId {
@@ -23,3 +25,11 @@ impl From<&str> for ClientId {
         Self(value.to_string())
     }
 }
+
+impl FromStr for ClientId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt::Debug;
+
+/// A client secret used to authenticate users.
+///
+/// Each environment will have its own client secret. Its [`Debug`] output is
+/// redacted so that it is not accidentally logged.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ClientSecret(String);
+
+impl ClientSecret {
+    /// Returns the value of the client secret.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Debug for ClientSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ClientSecret").field(&"[REDACTED]").finish()
+    }
+}
+
+impl From<String> for ClientSecret {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for ClientSecret {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ClientSecret;
+
+    #[test]
+    fn it_redacts_the_client_secret_in_debug_output() {
+        let client_secret = ClientSecret::from("secret_123456789");
+
+        assert_eq!(
+            format!("{client_secret:?}"),
+            r#"ClientSecret("[REDACTED]")"#
+        );
+        assert!(!format!("{client_secret:#?}").contains("secret_123456789"))
+    }
+
+    #[test]
+    fn it_exposes_the_client_secret() {
+        assert_eq!(
+            ClientSecret::from("secret_123456789").expose_secret(),
+            "secret_123456789"
+        )
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use thiserror::Error;
+
+/// A `state` parameter containing a JSON-serialized value encoded as URL-safe base64.
+///
+/// This can be used to pass structured data, such as a CSRF token or the page to
+/// return to, through an authentication flow and recover it in the callback.
+///
+/// # Examples
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use workos::EncodedState;
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct CallbackState {
+///     return_to: String,
+/// }
+///
+/// let state = EncodedState::encode(&CallbackState {
+///     return_to: "/dashboard".to_string(),
+/// })
+/// .unwrap();
+///
+/// // Pass `state.as_str()` as the `state` parameter, then in the callback:
+/// let decoded: CallbackState = EncodedState::from(state.as_str()).decode().unwrap();
+///
+/// assert_eq!(decoded.return_to, "/dashboard");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedState(String);
+
+/// An error returned from [`EncodedState::decode`].
+#[derive(Debug, Error)]
+pub enum DecodeStateError {
+    /// The state was not valid URL-safe base64.
+    #[error("state is not valid base64")]
+    Base64(#[from] base64::DecodeError),
+
+    /// The decoded state could not be deserialized.
+    #[error("state could not be deserialized")]
+    Json(#[from] serde_json::Error),
+}
+
+impl EncodedState {
+    /// Encodes the given value as JSON and then as URL-safe base64.
+    pub fn encode<S: Serialize>(state: &S) -> Result<Self, serde_json::Error> {
+        let json = serde_json::to_vec(state)?;
+
+        Ok(Self(URL_SAFE_NO_PAD.encode(json)))
+    }
+
+    /// Decodes the state back into a value of type `S`.
+    pub fn decode<S: DeserializeOwned>(&self) -> Result<S, DecodeStateError> {
+        let json = URL_SAFE_NO_PAD.decode(&self.0)?;
+
+        Ok(serde_json::from_slice(&json)?)
+    }
+
+    /// Returns the encoded state as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for EncodedState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for EncodedState {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for EncodedState {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use serde_json::Value;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_an_error_when_the_state_is_not_base64() {
+        let result = EncodedState::from("not base64!").decode::<Value>();
+
+        assert_matches!(result, Err(DecodeStateError::Base64(_)))
+    }
+
+    #[test]
+    fn it_returns_an_error_when_the_state_is_not_json() {
+        let result = EncodedState::from(URL_SAFE_NO_PAD.encode("not json")).decode::<Value>();
+
+        assert_matches!(result, Err(DecodeStateError::Json(_)))
+    }
+}
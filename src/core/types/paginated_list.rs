@@ -20,3 +20,54 @@ pub struct ListMetadata {
     /// The pagination cursor used to retrieve the next page of records.
     pub after: Option<String>,
 }
+
+impl<T> PaginatedList<T> {
+    /// Returns the number of items in the current page.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the current page has no items.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns `true` if there is a next page of records, which can be retrieved
+    /// using the [`after`](ListMetadata::after) cursor.
+    pub fn has_more(&self) -> bool {
+        self.metadata.after.is_some()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn list(data: Vec<u32>, after: Option<&str>) -> PaginatedList<u32> {
+        PaginatedList {
+            data,
+            metadata: ListMetadata {
+                before: None,
+                after: after.map(str::to_string),
+            },
+        }
+    }
+
+    #[test]
+    fn it_returns_the_number_of_items_in_the_page() {
+        assert_eq!(list(vec![1, 2, 3], None).len(), 3);
+        assert_eq!(list(vec![], None).len(), 0);
+    }
+
+    #[test]
+    fn it_returns_whether_the_page_is_empty() {
+        assert!(list(vec![], None).is_empty());
+        assert!(!list(vec![1], None).is_empty());
+    }
+
+    #[test]
+    fn it_returns_whether_there_are_more_pages() {
+        assert!(list(vec![1], Some("cursor_123")).has_more());
+        assert!(!list(vec![1], None).has_more());
+    }
+}
@@ -1,26 +1,39 @@
 use serde::Serialize;
 
 /// The parameters used to control pagination for a given paginated endpoint.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct PaginationParams<'a> {
     /// The order in which records should be paginated.
-    pub order: &'a PaginationOrder,
+    ///
+    /// Defaults to [`None`], which omits the `order` parameter so that the API's default
+    /// order is used. Use [`PaginationParams::with_order`] to send an explicit order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<&'a PaginationOrder>,
 
     /// The cursor after which records should be retrived.
     pub after: Option<&'a str>,
 
     /// The cursor before which records should be retrieved.
     pub before: Option<&'a str>,
+
+    /// The maximum number of records to return.
+    pub limit: Option<u32>,
 }
 
-impl<'a> Default for PaginationParams<'a> {
-    fn default() -> Self {
+impl<'a> PaginationParams<'a> {
+    /// Returns pagination parameters that explicitly send the given order.
+    pub fn with_order(order: &'a PaginationOrder) -> Self {
         Self {
-            order: &PaginationOrder::DEFAULT,
-            before: None,
-            after: None,
+            order: Some(order),
+            ..Default::default()
         }
     }
+
+    /// Returns pagination parameters that explicitly send the
+    /// [`PaginationOrder::Desc`] order.
+    pub fn desc() -> Self {
+        Self::with_order(&PaginationOrder::Desc)
+    }
 }
 
 /// The order in which records should be returned when paginating.
@@ -34,16 +47,11 @@ pub enum PaginationOrder {
     Desc,
 }
 
-impl PaginationOrder {
-    /// The default order to use for pagination.
-    pub(crate) const DEFAULT: PaginationOrder = PaginationOrder::Desc;
-}
-
 #[cfg(test)]
 mod test {
     use serde_json::json;
 
-    use crate::PaginationOrder;
+    use crate::{PaginationOrder, PaginationParams};
 
     #[test]
     fn pagination_order_properly_serializes_asc() {
@@ -60,4 +68,42 @@ mod test {
             json!("desc").to_string()
         )
     }
+
+    #[test]
+    fn pagination_params_explicitly_serialize_a_desc_order() {
+        assert_eq!(
+            serde_json::to_value(PaginationParams::desc()).unwrap(),
+            json!({
+                "order": "desc",
+                "after": null,
+                "before": null,
+                "limit": null
+            })
+        )
+    }
+
+    #[test]
+    fn pagination_params_explicitly_serialize_an_asc_order() {
+        assert_eq!(
+            serde_json::to_value(PaginationParams::with_order(&PaginationOrder::Asc)).unwrap(),
+            json!({
+                "order": "asc",
+                "after": null,
+                "before": null,
+                "limit": null
+            })
+        )
+    }
+
+    #[test]
+    fn pagination_params_omit_the_order_by_default() {
+        assert_eq!(
+            serde_json::to_value(PaginationParams::default()).unwrap(),
+            json!({
+                "after": null,
+                "before": null,
+                "limit": null
+            })
+        )
+    }
 }
@@ -4,9 +4,44 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 /// The raw attributes returned from the Identity or Directory Provider.
+///
+/// # Examples
+///
+/// ```
+/// # use workos::RawAttributes;
+/// # let raw_attributes: RawAttributes = serde_json::from_value(serde_json::json!({
+/// #     "department": "Engineering",
+/// #     "manager": { "email": "grace@foo-corp.com" }
+/// # }))
+/// # .unwrap();
+/// #[derive(serde::Deserialize)]
+/// struct Manager {
+///     email: String,
+/// }
+///
+/// let department = raw_attributes.get_str("department");
+/// let manager: Option<Manager> = raw_attributes
+///     .get("manager")
+///     .and_then(|manager| serde_json::from_value(manager.clone()).ok());
+///
+/// assert_eq!(department, Some("Engineering"));
+/// assert_eq!(manager.map(|manager| manager.email).as_deref(), Some("grace@foo-corp.com"));
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RawAttributes(pub HashMap<String, Value>);
 
+impl RawAttributes {
+    /// Returns the value of the attribute with the given name.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.0.get(name)
+    }
+
+    /// Returns the value of the attribute with the given name, if it is a string.
+    pub fn get_str(&self, name: &str) -> Option<&str> {
+        self.get(name).and_then(Value::as_str)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::HashMap;
@@ -79,4 +114,21 @@ mod test {
 
         assert_eq!(raw_attributes, RawAttributes(expected_raw_attributes))
     }
+
+    #[test]
+    fn it_gets_attributes_by_name() {
+        let raw_attributes: RawAttributes = serde_json::from_str(
+            &json!({
+                "name": "Developers",
+                "members": 12
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(raw_attributes.get("members"), Some(&json!(12)));
+        assert_eq!(raw_attributes.get_str("name"), Some("Developers"));
+        assert_eq!(raw_attributes.get_str("members"), None);
+        assert_eq!(raw_attributes.get("missing"), None)
+    }
 }
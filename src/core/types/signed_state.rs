@@ -0,0 +1,155 @@
+use std::fmt::Display;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use thiserror::Error;
+
+use crate::core::crypto::{Hmac, HmacAlgorithm};
+
+/// A `state` parameter signed with an HMAC so that tampering can be detected when it
+/// is passed back to the application.
+///
+/// The signed state has the form `<payload>.<signature>`, where the signature is the
+/// URL-safe base64 encoding of the HMAC-SHA256 of the payload. It can be used as the
+/// `state` for both SSO and Passwordless, and can be combined with
+/// [`EncodedState`](crate::EncodedState) to sign structured state.
+///
+/// The payload is signed but not encrypted, so it should not contain secrets.
+///
+/// This is only available when the `crypto` feature is enabled.
+///
+/// # Examples
+///
+/// ```
+/// use workos::SignedState;
+///
+/// let secret = b"a-secret-only-the-application-knows";
+///
+/// let state = SignedState::sign("return_to=/dashboard", secret);
+///
+/// // Pass `state.as_str()` as the `state` parameter, then in the callback:
+/// let returned_state = SignedState::from(state.as_str());
+/// let payload = returned_state.verify(secret).unwrap();
+///
+/// assert_eq!(payload, "return_to=/dashboard");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedState(String);
+
+/// An error returned from [`SignedState::verify`].
+#[derive(Debug, Error)]
+pub enum VerifyStateError {
+    /// The state does not contain a signature.
+    #[error("state is not signed")]
+    MissingSignature,
+
+    /// The signature does not match the payload.
+    #[error("state signature is invalid")]
+    InvalidSignature,
+}
+
+impl SignedState {
+    /// Signs the payload using the given secret.
+    pub fn sign(payload: &str, secret: &[u8]) -> Self {
+        let mut hmac = Hmac::new(HmacAlgorithm::Sha256, secret);
+        hmac.update(payload.as_bytes());
+        let signature = URL_SAFE_NO_PAD.encode(hmac.finish());
+
+        Self(format!("{}.{}", payload, signature))
+    }
+
+    /// Verifies the signature using the given secret, returning the payload if it is
+    /// valid.
+    pub fn verify(&self, secret: &[u8]) -> Result<&str, VerifyStateError> {
+        let (payload, signature) = self
+            .0
+            .rsplit_once('.')
+            .ok_or(VerifyStateError::MissingSignature)?;
+
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| VerifyStateError::InvalidSignature)?;
+
+        let mut hmac = Hmac::new(HmacAlgorithm::Sha256, secret);
+        hmac.update(payload.as_bytes());
+
+        if hmac.verify(&signature) {
+            Ok(payload)
+        } else {
+            Err(VerifyStateError::InvalidSignature)
+        }
+    }
+
+    /// Returns the signed state as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for SignedState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for SignedState {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for SignedState {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+
+    use super::*;
+
+    const SECRET: &[u8] = b"state_secret_123456789";
+
+    #[test]
+    fn it_verifies_a_validly_signed_state() {
+        let state = SignedState::sign("csrf=f3a1b2c4.return_to=/settings", SECRET);
+
+        assert_eq!(
+            state.verify(SECRET).unwrap(),
+            "csrf=f3a1b2c4.return_to=/settings"
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_tampered_payload() {
+        let state = SignedState::sign("return_to=/settings", SECRET);
+        let (_, signature) = state.as_str().rsplit_once('.').unwrap();
+
+        let tampered = SignedState::from(format!("return_to=/admin.{}", signature));
+
+        assert_matches!(
+            tampered.verify(SECRET),
+            Err(VerifyStateError::InvalidSignature)
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_state_signed_with_a_different_secret() {
+        let state = SignedState::sign("return_to=/settings", b"another_secret");
+
+        assert_matches!(
+            state.verify(SECRET),
+            Err(VerifyStateError::InvalidSignature)
+        )
+    }
+
+    #[test]
+    fn it_rejects_an_unsigned_state() {
+        assert_matches!(
+            SignedState::from("return_to=/settings").verify(SECRET),
+            Err(VerifyStateError::MissingSignature)
+        )
+    }
+}
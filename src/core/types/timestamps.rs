@@ -1,23 +1,43 @@
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// A UTC timestamp.
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+///
+/// Timestamps are ordered by the instant they represent, regardless of their UTC offset.
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Timestamp(pub DateTime<FixedOffset>);
 
+/// An error returned when a [`Timestamp`] cannot be parsed from a string.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid timestamp {input:?}, expected an RFC 3339 timestamp")]
+pub struct TimestampParseError {
+    /// The input that could not be parsed.
+    pub input: String,
+
+    /// The underlying parse error.
+    #[source]
+    pub source: chrono::ParseError,
+}
+
 impl TryFrom<String> for Timestamp {
-    type Error = chrono::ParseError;
+    type Error = TimestampParseError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        Ok(Self(DateTime::parse_from_rfc3339(&value)?))
+        Self::try_from(value.as_str())
     }
 }
 
 impl TryFrom<&str> for Timestamp {
-    type Error = chrono::ParseError;
+    type Error = TimestampParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Ok(Self(DateTime::parse_from_rfc3339(value)?))
+        DateTime::parse_from_rfc3339(value)
+            .map(Self)
+            .map_err(|source| TimestampParseError {
+                input: value.to_string(),
+                source,
+            })
     }
 }
 
@@ -35,7 +55,7 @@ pub struct Timestamps {
 mod test {
     use chrono::DateTime;
 
-    use super::Timestamp;
+    use super::{Timestamp, TimestampParseError};
 
     #[test]
     fn it_parses_a_timestamp_from_an_iso_string() {
@@ -43,7 +63,49 @@ mod test {
 
         assert_eq!(
             Timestamp::try_from(iso_string),
-            DateTime::parse_from_rfc3339(iso_string).map(Timestamp)
+            Ok(Timestamp(DateTime::parse_from_rfc3339(iso_string).unwrap()))
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_for_malformed_timestamps() {
+        for input in [
+            "",
+            "not a timestamp",
+            "2022-06-28",
+            "2022-13-28T19:07:33.155Z",
+            "2022-06-28T19:07:33.155",
+        ] {
+            let error = Timestamp::try_from(input).unwrap_err();
+
+            assert_eq!(error.input, input);
+        }
+    }
+
+    #[test]
+    fn it_includes_the_input_in_the_error_message() {
+        let error: TimestampParseError = Timestamp::try_from("yesterday".to_string()).unwrap_err();
+
+        assert!(error.to_string().contains("\"yesterday\""));
+    }
+
+    #[test]
+    fn it_sorts_timestamps_by_instant() {
+        let mut timestamps = vec![
+            Timestamp::try_from("2022-06-28T19:07:33.155Z").unwrap(),
+            Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+            Timestamp::try_from("2022-06-28T20:00:00.000+02:00").unwrap(),
+        ];
+
+        timestamps.sort();
+
+        assert_eq!(
+            timestamps,
+            vec![
+                Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+                Timestamp::try_from("2022-06-28T20:00:00.000+02:00").unwrap(),
+                Timestamp::try_from("2022-06-28T19:07:33.155Z").unwrap(),
+            ]
         )
     }
 }
@@ -3,7 +3,7 @@ use std::fmt::{Display, Write};
 use serde::{ser, Serialize, Serializer};
 
 /// A [`Vec`] that can be URL-encoded.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct UrlEncodableVec<T: Display>(Vec<T>);
 
 impl<T> Serialize for UrlEncodableVec<T>
@@ -70,7 +70,7 @@ mod test {
         let client = reqwest::Client::new();
 
         let response = client
-            .get(&server.url())
+            .get(server.url())
             .query(&List {
                 items: UrlEncodableVec(vec!["one", "two", "three"]),
             })
@@ -102,7 +102,7 @@ mod test {
         let client = reqwest::Client::new();
 
         let response = client
-            .get(&server.url())
+            .get(server.url())
             .query(&List {
                 items: Some(UrlEncodableVec(vec!["one", "two", "three"])),
             })
@@ -5,6 +5,8 @@
 mod operations;
 mod types;
 
+pub mod attributes;
+
 pub use operations::*;
 pub use types::*;
 
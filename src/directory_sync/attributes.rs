@@ -0,0 +1,135 @@
+//! Typed custom attributes for [`DirectoryUser`](crate::directory_sync::DirectoryUser)s.
+//!
+//! [WorkOS Docs: Custom Attributes](https://workos.com/docs/directory-sync/attributes/custom-attributes)
+
+use serde::{Deserialize, Serialize};
+
+/// Commonly mapped custom attributes for a
+/// [`DirectoryUser`](crate::directory_sync::DirectoryUser).
+///
+/// This can be used as the custom attributes type of a directory user, as
+/// `DirectoryUser<StandardScimAttributes>`, rather than defining the same struct in
+/// each integration.
+///
+/// Each attribute is [`None`] if it is not mapped for the directory, or if the
+/// Directory Provider did not provide a value for the user.
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use workos::directory_sync::attributes::StandardScimAttributes;
+///
+/// let attributes: StandardScimAttributes = serde_json::from_value(json!({
+///     "department": "Engineering",
+///     "job_title": "Software Engineer"
+/// }))?;
+///
+/// assert_eq!(attributes.department.as_deref(), Some("Engineering"));
+/// assert_eq!(attributes.manager_email, None);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StandardScimAttributes {
+    /// The department the user belongs to.
+    pub department: Option<String>,
+
+    /// The job title of the user.
+    #[serde(alias = "title")]
+    pub job_title: Option<String>,
+
+    /// The type of employment, such as `"Full-time"` or `"Contractor"`.
+    pub employee_type: Option<String>,
+
+    /// The division the user belongs to.
+    pub division: Option<String>,
+
+    /// The cost center the user belongs to.
+    pub cost_center: Option<String>,
+
+    /// The email address of the user's manager.
+    pub manager_email: Option<String>,
+
+    /// The phone number of the user.
+    pub phone_number: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::directory_sync::{DirectoryUser, DirectoryUserId};
+
+    use super::*;
+
+    #[test]
+    fn it_deserializes_a_directory_user_with_the_standard_scim_attributes() {
+        let directory_user: DirectoryUser<StandardScimAttributes> = serde_json::from_str(
+            &json!({
+                "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+                "idp_id": "2836",
+                "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+                "first_name": "Marcelina",
+                "last_name": "Davis",
+                "emails": [
+                    {
+                        "primary": true,
+                        "type": "work",
+                        "value": "marcelina@foo-corp.com"
+                    }
+                ],
+                "username": "marcelina@foo-corp.com",
+                "state": "active",
+                "created_at": "2021-06-25T19:07:33.155Z",
+                "updated_at": "2021-06-25T19:07:33.155Z",
+                "custom_attributes": {
+                    "department": "Engineering",
+                    "job_title": "Software Engineer",
+                    "employee_type": "Full-time",
+                    "manager_email": "kathleen@foo-corp.com",
+                    "phone_number": "+15555555555",
+                    "favorite_color": "blue"
+                },
+                "raw_attributes": {
+                    "schemas": ["urn:ietf:params:scim:schemas:core:2.0:User"],
+                    "userName": "marcelina@foo-corp.com",
+                    "title": "Software Engineer",
+                    "urn:ietf:params:scim:schemas:extension:enterprise:2.0:User": {
+                        "department": "Engineering",
+                        "manager": {
+                            "value": "2835"
+                        }
+                    }
+                }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            directory_user.id,
+            DirectoryUserId::from("directory_user_01E1JG7J09H96KYP8HM9B0G5SJ")
+        );
+        assert_eq!(
+            directory_user.custom_attributes,
+            StandardScimAttributes {
+                department: Some("Engineering".to_string()),
+                job_title: Some("Software Engineer".to_string()),
+                employee_type: Some("Full-time".to_string()),
+                division: None,
+                cost_center: None,
+                manager_email: Some("kathleen@foo-corp.com".to_string()),
+                phone_number: Some("+15555555555".to_string()),
+            }
+        )
+    }
+
+    #[test]
+    fn it_deserializes_a_title_as_the_job_title() {
+        let attributes: StandardScimAttributes =
+            serde_json::from_value(json!({ "title": "Software Engineer" })).unwrap();
+
+        assert_eq!(attributes.job_title.as_deref(), Some("Software Engineer"))
+    }
+}
@@ -3,7 +3,7 @@ use serde::Serialize;
 use thiserror::Error;
 
 use crate::directory_sync::{DirectoryId, DirectorySync};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`DeleteDirectory`].
 #[derive(Debug, Serialize)]
@@ -68,9 +68,10 @@ impl<'a> DeleteDirectory for DirectorySync<'a> {
             .client()
             .delete(url)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_instrumented(self.workos)
             .await?
-            .handle_unauthorized_or_generic_error()?;
+            .handle_unauthorized_or_generic_error()
+            .await?;
 
         Ok(())
     }
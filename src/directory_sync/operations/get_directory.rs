@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::directory_sync::{Directory, DirectoryId, DirectorySync};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetDirectory`].
 #[derive(Debug, Error)]
@@ -53,10 +53,11 @@ impl<'a> GetDirectory for DirectorySync<'a> {
             .client()
             .get(url)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_instrumented(self.workos)
             .await?
-            .handle_unauthorized_or_generic_error()?
-            .json::<Directory>()
+            .handle_unauthorized_or_generic_error()
+            .await?
+            .parse_json::<Directory, _>()
             .await?;
 
         Ok(directory)
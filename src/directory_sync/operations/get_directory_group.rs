@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::directory_sync::{DirectoryGroup, DirectoryGroupId, DirectorySync};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetDirectoryGroup`].
 #[derive(Debug, Error)]
@@ -61,10 +61,11 @@ impl<'a> GetDirectoryGroup for DirectorySync<'a> {
             .client()
             .get(url)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_instrumented(self.workos)
             .await?
-            .handle_unauthorized_or_generic_error()?
-            .json::<DirectoryGroup>()
+            .handle_unauthorized_or_generic_error()
+            .await?
+            .parse_json::<DirectoryGroup, _>()
             .await?;
 
         Ok(directory_group)
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::directory_sync::{DirectorySync, DirectoryUser, DirectoryUserId};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetDirectoryUser`].
 #[derive(Debug, Error)]
@@ -61,10 +61,11 @@ impl<'a> GetDirectoryUser for DirectorySync<'a> {
             .client()
             .get(url)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_instrumented(self.workos)
             .await?
-            .handle_unauthorized_or_generic_error()?
-            .json::<DirectoryUser>()
+            .handle_unauthorized_or_generic_error()
+            .await?
+            .parse_json::<DirectoryUser, _>()
             .await?;
 
         Ok(directory_user)
@@ -1,13 +1,16 @@
+use std::convert::Infallible;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use crate::directory_sync::DirectoryType;
 use crate::organizations::OrganizationId;
-use crate::{KnownOrUnknown, Timestamps};
+use crate::{KnownOrUnknown, Timestamp, Timestamps};
 
 /// The ID of a [`Directory`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct DirectoryId(String);
 
 impl Display for DirectoryId {
@@ -28,8 +31,17 @@ impl From<&str> for DirectoryId {
     }
 }
 
+impl FromStr for DirectoryId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
 /// The state of a [`Directory`].
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 #[serde(rename_all = "snake_case")]
 pub enum DirectoryState {
     /// The directory is inactve.
@@ -51,11 +63,24 @@ pub enum DirectoryState {
 }
 
 /// [WorkOS Docs: Directory](https://workos.com/docs/reference/directory-sync/directory)
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Directories compare equal only when all of their fields match. To look up or
+/// deduplicate directories by identity, key them by their [`DirectoryId`], for
+/// example in a `HashMap<DirectoryId, Directory>`.
+///
+/// This type is also used for the directory in
+/// [`dsync.*` webhooks](crate::webhooks::DirectoryActivatedWebhook).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Directory {
     /// The ID of the directory.
     pub id: DirectoryId,
 
+    /// The type of the object, which is always `"directory"` for directories.
+    ///
+    /// This is empty if the payload did not include it.
+    #[serde(default)]
+    pub object: String,
+
     /// The ID of the associated [`Organization`](crate::organizations::Organization) for this directory.
     pub organization_id: Option<OrganizationId>,
 
@@ -71,11 +96,60 @@ pub struct Directory {
     /// The URL associated with an Enterprise Client.
     pub domain: Option<String>,
 
+    /// The external key of the directory, which some directory types use to identify
+    /// the directory in their endpoint URLs.
+    pub external_key: Option<String>,
+
     /// The timestamps for the Directory.
     #[serde(flatten)]
     pub timestamps: Timestamps,
 }
 
+impl Directory {
+    /// Returns the ID of the associated [`Organization`](crate::organizations::Organization)
+    /// for this directory, or an error if it does not have one.
+    ///
+    /// This can be used in place of unwrapping
+    /// [`organization_id`](Self::organization_id) where the directory is known to
+    /// belong to an organization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos::directory_sync::{Directory, MissingOrganizationIdError};
+    /// # fn run(directory: &Directory) -> Result<(), MissingOrganizationIdError> {
+    /// let organization_id = directory.require_organization_id()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn require_organization_id(&self) -> Result<&OrganizationId, MissingOrganizationIdError> {
+        self.organization_id
+            .as_ref()
+            .ok_or_else(|| MissingOrganizationIdError {
+                directory_id: self.id.clone(),
+            })
+    }
+
+    /// Returns the timestamp indicating when the directory was created.
+    pub fn created_at(&self) -> &Timestamp {
+        &self.timestamps.created_at
+    }
+
+    /// Returns the timestamp indicating when the directory was last updated.
+    pub fn updated_at(&self) -> &Timestamp {
+        &self.timestamps.updated_at
+    }
+}
+
+/// An error returned from [`Directory::require_organization_id`] when the directory
+/// does not have an associated organization.
+#[derive(Debug, Error)]
+#[error("directory {directory_id} does not have an organization ID")]
+pub struct MissingOrganizationIdError {
+    /// The ID of the directory.
+    pub directory_id: DirectoryId,
+}
+
 #[cfg(test)]
 mod test {
     use serde_json::json;
@@ -86,10 +160,28 @@ mod test {
 
     use super::{Directory, DirectoryId, DirectoryState};
 
+    fn directory(organization_id: Option<&str>) -> Directory {
+        Directory {
+            id: DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+            object: "directory".to_string(),
+            domain: None,
+            external_key: None,
+            organization_id: organization_id.map(OrganizationId::from),
+            r#type: KnownOrUnknown::Known(DirectoryType::BambooHr),
+            name: "Foo Corp".to_string(),
+            state: KnownOrUnknown::Known(DirectoryState::Active),
+            timestamps: Timestamps {
+                created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+                updated_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+            },
+        }
+    }
+
     #[test]
     fn it_deserializes_a_directory() {
         let directory: Directory = serde_json::from_str(
             &json!({
+              "object": "directory",
               "id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
               "domain": "foo-corp.com",
               "name": "Foo Corp",
@@ -107,7 +199,9 @@ mod test {
             directory,
             Directory {
                 id: DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+                object: "directory".to_string(),
                 domain: Some("foo-corp.com".to_string()),
+                external_key: None,
                 organization_id: Some(OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")),
                 r#type: KnownOrUnknown::Known(DirectoryType::BambooHr),
                 name: "Foo Corp".to_string(),
@@ -142,4 +236,153 @@ mod test {
             KnownOrUnknown::Unknown("UnknownType".to_string())
         )
     }
+
+    #[test]
+    fn it_deserializes_the_external_key() {
+        let directory: Directory = serde_json::from_str(
+            &json!({
+              "id": "directory_01E8CS3GSBEBZ1F1CZAEE3KHDG",
+              "domain": "foo-corp.com",
+              "external_key": "r3NDlInUnAe6i4wG",
+              "name": "Foo Corp",
+              "organization_id": "org_01EHZNVPK3SFK441A1RGBFPANT",
+              "object": "directory",
+              "state": "linked",
+              "type": "okta scim v2.0",
+              "created_at": "2021-06-25T19:09:33.155Z",
+              "updated_at": "2021-06-25T19:10:33.155Z"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(directory.external_key, Some("r3NDlInUnAe6i4wG".to_string()))
+    }
+
+    #[test]
+    fn it_deserializes_a_directory_without_a_domain() {
+        let directory: Directory = serde_json::from_str(
+            &json!({
+              "object": "directory",
+              "id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "name": "Foo Corp",
+              "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+              "state": "linked",
+              "type": "bamboohr",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(directory.domain, None)
+    }
+
+    #[test]
+    fn it_deserializes_the_directory_from_a_webhook_payload() {
+        let directory: Directory = serde_json::from_str(
+            &json!({
+              "id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "name": "Foo Corp",
+              "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+              "state": "inactive",
+              "type": "bamboohr",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            directory,
+            Directory {
+                id: DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+                object: "".to_string(),
+                domain: None,
+                external_key: None,
+                organization_id: Some(OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")),
+                r#type: KnownOrUnknown::Known(DirectoryType::BambooHr),
+                name: "Foo Corp".to_string(),
+                state: KnownOrUnknown::Known(DirectoryState::Inactive),
+                timestamps: Timestamps {
+                    created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+                    updated_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+                }
+            }
+        )
+    }
+
+    #[test]
+    fn it_deserializes_a_directory_that_is_being_deleted() {
+        let directory: Directory = serde_json::from_str(
+            &json!({
+              "object": "directory",
+              "id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "name": "Foo Corp",
+              "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+              "state": "deleting",
+              "type": "bamboohr",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            directory.state,
+            KnownOrUnknown::Known(DirectoryState::Deleting)
+        )
+    }
+
+    #[test]
+    fn it_deserializes_unknown_directory_states() {
+        let directory: Directory = serde_json::from_str(
+            &json!({
+              "object": "directory",
+              "id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "name": "Foo Corp",
+              "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+              "state": "archived",
+              "type": "bamboohr",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            directory.state,
+            KnownOrUnknown::Unknown("archived".to_string())
+        )
+    }
+
+    #[test]
+    fn it_returns_the_organization_id_when_the_directory_has_one() {
+        let directory = directory(Some("org_01EHZNVPK3SFK441A1RGBFSHRT"));
+
+        assert_eq!(
+            directory.require_organization_id().unwrap(),
+            &OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_when_the_directory_does_not_have_an_organization_id() {
+        let directory = directory(None);
+
+        let err = directory.require_organization_id().unwrap_err();
+
+        assert_eq!(
+            err.directory_id,
+            DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74")
+        );
+        assert_eq!(
+            err.to_string(),
+            "directory directory_01ECAZ4NV9QMV47GW873HDCX74 does not have an organization ID"
+        )
+    }
 }
@@ -1,13 +1,15 @@
+use std::convert::Infallible;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
 use crate::directory_sync::DirectoryId;
 use crate::organizations::OrganizationId;
-use crate::{RawAttributes, Timestamps};
+use crate::{RawAttributes, Timestamp, Timestamps};
 
 /// The ID of a [`DirectoryGroup`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct DirectoryGroupId(String);
 
 impl Display for DirectoryGroupId {
@@ -28,6 +30,14 @@ impl From<&str> for DirectoryGroupId {
     }
 }
 
+impl FromStr for DirectoryGroupId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
 /// [WorkOS Docs: Directory Group](https://workos.com/docs/reference/directory-sync/directory-group)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DirectoryGroup {
@@ -55,6 +65,18 @@ pub struct DirectoryGroup {
     pub raw_attributes: RawAttributes,
 }
 
+impl DirectoryGroup {
+    /// Returns the timestamp indicating when the directory group was created.
+    pub fn created_at(&self) -> &Timestamp {
+        &self.timestamps.created_at
+    }
+
+    /// Returns the timestamp indicating when the directory group was last updated.
+    pub fn updated_at(&self) -> &Timestamp {
+        &self.timestamps.updated_at
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::HashMap;
@@ -1,7 +1,21 @@
+use std::fmt::Display;
+
 use serde::{Deserialize, Serialize};
 
+/// The category of provider a [`DirectoryType`] belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DirectoryCategory {
+    /// A human resources information system, such as BambooHR or Workday.
+    Hris,
+
+    /// An identity provider, such as Okta or Google Workspace.
+    IdentityProvider,
+}
+
 /// The type of a [`Directory`](crate::directory_sync::Directory).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum DirectoryType {
     /// Azure AD SCIM v2.0.
     ///
@@ -99,3 +113,114 @@ pub enum DirectoryType {
     #[serde(rename = "workday")]
     Workday,
 }
+
+impl DirectoryType {
+    /// Returns the string used to represent the directory type in the WorkOS API, such as
+    /// `"azure scim v2.0"`.
+    pub fn as_api_str(&self) -> &'static str {
+        match self {
+            Self::AzureScimV2_0 => "azure scim v2.0",
+            Self::BambooHr => "bamboohr",
+            Self::BreatheHr => "breathe hr",
+            Self::CyberArkScimV2_0 => "cyberark scim v2.0",
+            Self::GenericScimV1_1 => "generic scim v1.1",
+            Self::GenericScimV2_0 => "generic scim v2.0",
+            Self::GoogleWorkspace => "gsuite directory",
+            Self::Hibob => "hibob",
+            Self::JumpCloudScimV2_0 => "jump cloud scim v2.0",
+            Self::OktaScimV1_1 => "okta scim v1.1",
+            Self::OktaScimV2_0 => "okta scim v2.0",
+            Self::OneLoginScimV2_0 => "onelogin scim v2.0",
+            Self::PeopleHr => "people hr",
+            Self::PingFederateScimV2_0 => "pingfederate scim v2.0",
+            Self::Rippling => "rippling",
+            Self::Workday => "workday",
+        }
+    }
+
+    /// Returns the [`DirectoryCategory`] of the directory type, which can be used to
+    /// group directory types when presenting them.
+    pub fn category(&self) -> DirectoryCategory {
+        match self {
+            Self::BambooHr
+            | Self::BreatheHr
+            | Self::Hibob
+            | Self::PeopleHr
+            | Self::Rippling
+            | Self::Workday => DirectoryCategory::Hris,
+            Self::AzureScimV2_0
+            | Self::CyberArkScimV2_0
+            | Self::GenericScimV1_1
+            | Self::GenericScimV2_0
+            | Self::GoogleWorkspace
+            | Self::JumpCloudScimV2_0
+            | Self::OktaScimV1_1
+            | Self::OktaScimV2_0
+            | Self::OneLoginScimV2_0
+            | Self::PingFederateScimV2_0 => DirectoryCategory::IdentityProvider,
+        }
+    }
+}
+
+impl Display for DirectoryType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_api_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{DirectoryCategory, DirectoryType};
+
+    #[test]
+    fn it_returns_the_api_string_matching_the_serialized_value() {
+        for directory_type in [
+            DirectoryType::GoogleWorkspace,
+            DirectoryType::OktaScimV2_0,
+            DirectoryType::BambooHr,
+            DirectoryType::JumpCloudScimV2_0,
+        ] {
+            assert_eq!(
+                json!(directory_type.as_api_str()),
+                serde_json::to_value(&directory_type).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn it_displays_the_api_string() {
+        assert_eq!(
+            DirectoryType::GoogleWorkspace.to_string(),
+            "gsuite directory"
+        )
+    }
+
+    #[test]
+    fn it_categorizes_hris_directory_types() {
+        for directory_type in [
+            DirectoryType::BambooHr,
+            DirectoryType::Hibob,
+            DirectoryType::Rippling,
+            DirectoryType::Workday,
+        ] {
+            assert_eq!(directory_type.category(), DirectoryCategory::Hris);
+        }
+    }
+
+    #[test]
+    fn it_categorizes_identity_provider_directory_types() {
+        for directory_type in [
+            DirectoryType::AzureScimV2_0,
+            DirectoryType::GenericScimV2_0,
+            DirectoryType::GoogleWorkspace,
+            DirectoryType::OktaScimV2_0,
+        ] {
+            assert_eq!(
+                directory_type.category(),
+                DirectoryCategory::IdentityProvider
+            );
+        }
+    }
+}
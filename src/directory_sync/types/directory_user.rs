@@ -1,15 +1,17 @@
 use std::collections::HashMap;
+use std::convert::Infallible;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::directory_sync::DirectoryId;
 use crate::organizations::OrganizationId;
-use crate::{KnownOrUnknown, RawAttributes, Timestamps};
+use crate::{KnownOrUnknown, RawAttributes, Timestamp, Timestamps};
 
 /// The ID of a [`DirectoryUser`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct DirectoryUserId(String);
 
 impl Display for DirectoryUserId {
@@ -30,6 +32,14 @@ impl From<&str> for DirectoryUserId {
     }
 }
 
+impl FromStr for DirectoryUserId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
 /// [WorkOS Docs: Directory User](https://workos.com/docs/reference/directory-sync/directory-user)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DirectoryUser<TCustomAttributes = HashMap<String, Value>> {
@@ -72,6 +82,18 @@ pub struct DirectoryUser<TCustomAttributes = HashMap<String, Value>> {
     pub timestamps: Timestamps,
 }
 
+impl<TCustomAttributes> DirectoryUser<TCustomAttributes> {
+    /// Returns the timestamp indicating when the directory user was created.
+    pub fn created_at(&self) -> &Timestamp {
+        &self.timestamps.created_at
+    }
+
+    /// Returns the timestamp indicating when the directory user was last updated.
+    pub fn updated_at(&self) -> &Timestamp {
+        &self.timestamps.updated_at
+    }
+}
+
 impl DirectoryUser {
     /// Returns the first primary email for the [`DirectoryUser`].
     ///
@@ -79,10 +101,21 @@ impl DirectoryUser {
     pub fn primary_email(&self) -> Option<&DirectoryUserEmail> {
         self.emails.iter().find(|email| email.primary == Some(true))
     }
+
+    /// Returns the first email of the given type for the [`DirectoryUser`], such as
+    /// `"work"` or `"personal"`.
+    ///
+    /// Returns [`None`] if the directory user does not have an email of that type.
+    pub fn email_by_type(&self, ty: &str) -> Option<&DirectoryUserEmail> {
+        self.emails
+            .iter()
+            .find(|email| email.r#type.as_deref() == Some(ty))
+    }
 }
 
 /// The state of a [`DirectoryUser`].
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 #[serde(rename_all = "snake_case")]
 pub enum DirectoryUserState {
     /// The directory user is active.
@@ -315,4 +348,71 @@ mod test {
 
         assert_eq!(primary_email, None)
     }
+
+    fn directory_user_with_emails(emails: Vec<DirectoryUserEmail>) -> DirectoryUser {
+        DirectoryUser {
+            id: DirectoryUserId::from("directory_user_01E1JG7J09H96KYP8HM9B0G5SJ"),
+            idp_id: "2836".to_string(),
+            directory_id: DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+            organization_id: Some(OrganizationId::from("org_01EZTR6WYX1A0DSE2CYMGXQ24Y")),
+            username: Some("marcelina@foo-corp.com".to_string()),
+            emails,
+            first_name: Some("Marcelina".to_string()),
+            last_name: Some("Davis".to_string()),
+            state: KnownOrUnknown::Known(DirectoryUserState::Active),
+            custom_attributes: HashMap::new(),
+            raw_attributes: RawAttributes(HashMap::new()),
+            timestamps: Timestamps {
+                created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+                updated_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+            },
+        }
+    }
+
+    #[test]
+    fn it_returns_the_email_of_the_given_type() {
+        let directory_user = directory_user_with_emails(vec![
+            DirectoryUserEmail {
+                primary: Some(true),
+                r#type: Some("work".to_string()),
+                value: Some("marcelina@foo-corp.com".to_string()),
+            },
+            DirectoryUserEmail {
+                primary: Some(false),
+                r#type: Some("personal".to_string()),
+                value: Some("marcelina@example.com".to_string()),
+            },
+        ]);
+
+        assert_eq!(
+            directory_user
+                .email_by_type("personal")
+                .and_then(|email| email.value.as_deref()),
+            Some("marcelina@example.com")
+        );
+        assert_eq!(
+            directory_user
+                .email_by_type("work")
+                .and_then(|email| email.value.as_deref()),
+            Some("marcelina@foo-corp.com")
+        )
+    }
+
+    #[test]
+    fn it_returns_none_when_the_user_does_not_have_an_email_of_the_given_type() {
+        let directory_user = directory_user_with_emails(vec![
+            DirectoryUserEmail {
+                primary: Some(true),
+                r#type: Some("work".to_string()),
+                value: Some("marcelina@foo-corp.com".to_string()),
+            },
+            DirectoryUserEmail {
+                primary: Some(false),
+                r#type: None,
+                value: Some("marcelina@example.com".to_string()),
+            },
+        ]);
+
+        assert_eq!(directory_user.email_by_type("personal"), None)
+    }
 }
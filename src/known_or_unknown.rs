@@ -2,6 +2,9 @@ use serde::{Deserialize, Serialize};
 
 /// `KnownOrUnknown` is a type that respresents either a known value ([`Known`](KnownOrUnknown::Known))
 /// or an unknown value ([`Unknown`](KnownOrUnknown::Unknown)).
+///
+/// Both variants serialize as their inner value, so an unknown value is serialized
+/// exactly as it was received.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum KnownOrUnknown<K, U> {
@@ -11,3 +14,36 @@ pub enum KnownOrUnknown<K, U> {
     /// An unknown value.
     Unknown(U),
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::sso::ConnectionType;
+
+    use super::KnownOrUnknown;
+
+    #[test]
+    fn it_round_trips_a_known_value() {
+        let value: KnownOrUnknown<ConnectionType, String> =
+            serde_json::from_value(json!("OktaSAML")).unwrap();
+
+        assert_eq!(value, KnownOrUnknown::Known(ConnectionType::OktaSaml));
+        assert_eq!(serde_json::to_value(&value).unwrap(), json!("OktaSAML"))
+    }
+
+    #[test]
+    fn it_round_trips_an_unknown_value() {
+        let value: KnownOrUnknown<ConnectionType, String> =
+            serde_json::from_value(json!("NewProviderSAML")).unwrap();
+
+        assert_eq!(
+            value,
+            KnownOrUnknown::Unknown("NewProviderSAML".to_string())
+        );
+        assert_eq!(
+            serde_json::to_value(&value).unwrap(),
+            json!("NewProviderSAML")
+        )
+    }
+}
@@ -1,4 +1,63 @@
 //! The official SDK for interacting with the [WorkOS](https://workos.com) API.
+//!
+//! # Testing
+//!
+//! Each operation is a trait, such as [`GetOrganization`](organizations::GetOrganization),
+//! that is implemented by the corresponding API module. Code that depends on an operation
+//! can accept any implementation of its trait, which allows tests to substitute a fake
+//! implementation instead of running a mock HTTP server. The operation traits are object
+//! safe, so they can also be stored as trait objects such as `Box<dyn GetOrganization>`.
+//!
+//! ```
+//! use async_trait::async_trait;
+//! use workos::organizations::{
+//!     GetOrganization, GetOrganizationError, Organization, OrganizationId,
+//! };
+//! use workos::WorkOsResult;
+//!
+//! /// Application code that depends on the operation rather than on the client.
+//! async fn organization_name(
+//!     organizations: &(dyn GetOrganization + Sync),
+//!     id: &OrganizationId,
+//! ) -> WorkOsResult<String, GetOrganizationError> {
+//!     let organization = organizations.get_organization(id).await?;
+//!
+//!     Ok(organization.name)
+//! }
+//!
+//! /// A fake that returns a fixed organization.
+//! struct FakeOrganizations(Organization);
+//!
+//! #[async_trait]
+//! impl GetOrganization for FakeOrganizations {
+//!     async fn get_organization(
+//!         &self,
+//!         _id: &OrganizationId,
+//!     ) -> WorkOsResult<Organization, GetOrganizationError> {
+//!         Ok(self.0.clone())
+//!     }
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let organization: Organization = serde_json::from_value(serde_json::json!({
+//!     "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+//!     "object": "organization",
+//!     "name": "Foo Corp",
+//!     "created_at": "2021-06-25T19:07:33.155Z",
+//!     "updated_at": "2021-06-25T19:07:33.155Z",
+//!     "domains": []
+//! }))
+//! .unwrap();
+//!
+//! let fake = FakeOrganizations(organization);
+//! let name = organization_name(&fake, &OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+//!     .await
+//!     .unwrap();
+//!
+//! assert_eq!(name, "Foo Corp");
+//! # }
+//! ```
 
 #![warn(missing_docs)]
 
@@ -11,7 +70,10 @@ pub mod directory_sync;
 pub mod mfa;
 pub mod organizations;
 pub mod passwordless;
+pub mod prelude;
 pub mod sso;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod user_management;
 pub mod webhooks;
 
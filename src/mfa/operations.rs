@@ -1,7 +1,13 @@
 mod challenge_factor;
+mod enroll_and_challenge;
 mod enroll_factor;
+mod get_factor;
+mod re_challenge;
 mod verify_challenge;
 
 pub use challenge_factor::*;
+pub use enroll_and_challenge::*;
 pub use enroll_factor::*;
+pub use get_factor::*;
+pub use re_challenge::*;
 pub use verify_challenge::*;
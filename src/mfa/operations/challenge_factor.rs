@@ -3,7 +3,7 @@ use serde::Serialize;
 use thiserror::Error;
 
 use crate::mfa::{AuthenticationChallenge, AuthenticationFactorId, Mfa};
-use crate::{ResponseExt, WorkOsResult};
+use crate::{RequestExt, ResponseExt, WorkOsResult};
 
 /// The type of authentication factor to challenge.
 #[derive(Debug, Serialize)]
@@ -90,10 +90,11 @@ impl<'a> ChallengeFactor for Mfa<'a> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_instrumented(self.workos)
             .await?
-            .handle_unauthorized_or_generic_error()?
-            .json::<AuthenticationChallenge>()
+            .handle_unauthorized_or_generic_error()
+            .await?
+            .parse_json::<AuthenticationChallenge, _>()
             .await?;
 
         Ok(challenge)
@@ -1,10 +1,10 @@
 use async_trait::async_trait;
 use reqwest::{Response, StatusCode};
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 use thiserror::Error;
 
 use crate::mfa::{AuthenticationFactor, Mfa};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestExt, ResponseExt, ValidationError, WorkOsError, WorkOsResult};
 
 /// The parameters for [`EnrollFactor`].
 #[derive(Debug, Serialize)]
@@ -50,12 +50,6 @@ impl From<EnrollFactorError> for WorkOsError<EnrollFactorError> {
     }
 }
 
-#[derive(Debug, Deserialize)]
-struct WorkOsApiError {
-    pub code: String,
-    pub message: String,
-}
-
 #[async_trait]
 trait HandleEnrollFactorError
 where
@@ -71,15 +65,15 @@ impl HandleEnrollFactorError for Response {
             Ok(_) => Ok(self),
             Err(err) => match err.status() {
                 Some(StatusCode::UNPROCESSABLE_ENTITY) => {
-                    let error = self.json::<WorkOsApiError>().await?;
+                    let error = self.parse_json::<ValidationError, _>().await?;
 
-                    Err(match error.code.as_str() {
-                        "invalid_phone_number" => {
+                    Err(match error.code.as_deref() {
+                        Some("invalid_phone_number") => {
                             WorkOsError::Operation(EnrollFactorError::InvalidPhoneNumber {
-                                message: error.message,
+                                message: error.message.unwrap_or_default(),
                             })
                         }
-                        _ => WorkOsError::RequestError(err),
+                        _ => WorkOsError::Validation(error),
                     })
                 }
                 _ => Err(WorkOsError::RequestError(err)),
@@ -134,12 +128,13 @@ impl<'a> EnrollFactor for Mfa<'a> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_instrumented(self.workos)
             .await?
             .handle_unauthorized_error()?
+            .handle_service_unavailable_error()?
             .handle_enroll_factor_error()
             .await?
-            .json::<AuthenticationFactor>()
+            .parse_json::<AuthenticationFactor, _>()
             .await?;
 
         Ok(factor)
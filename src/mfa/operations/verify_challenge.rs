@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::mfa::{AuthenticationChallenge, AuthenticationChallengeId, Mfa, MfaCode};
-use crate::{ResponseExt, WorkOsResult};
+use crate::{RequestExt, ResponseExt, WorkOsResult};
 
 /// The response for [`VerifyChallenge`].
 #[derive(Debug, Serialize, Deserialize)]
@@ -14,6 +14,10 @@ pub struct VerifyChallengeResponse {
     /// Whether the challenge was verified successfully.
     #[serde(rename = "valid")]
     pub is_valid: bool,
+
+    /// The reason the challenge could not be verified, such as the code having expired,
+    /// if one was returned.
+    pub reason: Option<String>,
 }
 
 /// The parameters for [`VerifyChallenge`].
@@ -82,10 +86,11 @@ impl<'a> VerifyChallenge for Mfa<'a> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_instrumented(self.workos)
+            .await?
+            .handle_unauthorized_or_generic_error()
             .await?
-            .handle_unauthorized_or_generic_error()?
-            .json::<VerifyChallengeResponse>()
+            .parse_json::<VerifyChallengeResponse, _>()
             .await?;
 
         Ok(verify_response)
@@ -151,4 +156,45 @@ mod test {
             AuthenticationChallengeId::from("auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5")
         )
     }
+
+    #[test]
+    fn it_deserializes_an_invalid_verification_with_a_reason() {
+        let response: VerifyChallengeResponse = serde_json::from_value(json!({
+          "challenge": {
+            "object": "authentication_challenge",
+            "id": "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+            "created_at": "2022-02-15T15:26:53.274Z",
+            "updated_at": "2022-02-15T15:26:53.274Z",
+            "expires_at": "2022-02-15T15:36:53.279Z",
+            "authentication_factor_id": "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ"
+          },
+          "valid": false,
+          "reason": "authentication_challenge_expired"
+        }))
+        .unwrap();
+
+        assert!(!response.is_valid);
+        assert_eq!(
+            response.reason,
+            Some("authentication_challenge_expired".to_string())
+        )
+    }
+
+    #[test]
+    fn it_deserializes_a_verification_without_a_reason() {
+        let response: VerifyChallengeResponse = serde_json::from_value(json!({
+          "challenge": {
+            "object": "authentication_challenge",
+            "id": "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+            "created_at": "2022-02-15T15:26:53.274Z",
+            "updated_at": "2022-02-15T15:26:53.274Z",
+            "expires_at": "2022-02-15T15:36:53.279Z",
+            "authentication_factor_id": "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ"
+          },
+          "valid": true
+        }))
+        .unwrap();
+
+        assert_eq!(response.reason, None)
+    }
 }
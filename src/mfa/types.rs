@@ -1,7 +1,9 @@
 mod authentication_challenge;
 mod authentication_factor;
 mod mfa_code;
+mod totp_uri;
 
 pub use authentication_challenge::*;
 pub use authentication_factor::*;
 pub use mfa_code::*;
+pub use totp_uri::*;
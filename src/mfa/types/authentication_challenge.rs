@@ -1,4 +1,6 @@
+use std::convert::Infallible;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
@@ -6,7 +8,7 @@ use crate::mfa::AuthenticationFactorId;
 use crate::{Timestamp, Timestamps};
 
 /// The ID of an [`AuthenticationChallenge`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct AuthenticationChallengeId(String);
 
 impl Display for AuthenticationChallengeId {
@@ -27,6 +29,14 @@ impl From<&str> for AuthenticationChallengeId {
     }
 }
 
+impl FromStr for AuthenticationChallengeId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
 /// [WorkOS Docs: Authentication Challenge](https://workos.com/docs/reference/mfa/authentication-challenge)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AuthenticationChallenge {
@@ -34,6 +44,9 @@ pub struct AuthenticationChallenge {
     pub id: AuthenticationChallengeId,
 
     /// The ID of the authentication factor for which the challenge was issued.
+    ///
+    /// The challenge does not indicate the type of the factor it was issued for.
+    /// Use [`GetFactor`](crate::mfa::GetFactor) to retrieve the factor and its type.
     pub authentication_factor_id: AuthenticationFactorId,
 
     /// The timestamp when the authentication challenge will expire.
@@ -46,6 +59,18 @@ pub struct AuthenticationChallenge {
     pub timestamps: Timestamps,
 }
 
+impl AuthenticationChallenge {
+    /// Returns the timestamp indicating when the authentication challenge was created.
+    pub fn created_at(&self) -> &Timestamp {
+        &self.timestamps.created_at
+    }
+
+    /// Returns the timestamp indicating when the authentication challenge was last updated.
+    pub fn updated_at(&self) -> &Timestamp {
+        &self.timestamps.updated_at
+    }
+}
+
 #[cfg(test)]
 mod test {
     use serde_json::json;
@@ -84,4 +109,22 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn it_deserializes_an_authentication_challenge_for_a_totp_factor() {
+        let challenge: AuthenticationChallenge = serde_json::from_str(
+            &json!({
+              "object": "authentication_challenge",
+              "id": "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+              "authentication_factor_id": "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ",
+              "expires_at": null,
+              "created_at": "2022-02-15T15:26:53.274Z",
+              "updated_at": "2022-02-15T15:26:53.274Z"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(challenge.expires_at, None)
+    }
 }
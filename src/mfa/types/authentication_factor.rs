@@ -1,11 +1,15 @@
+use std::convert::Infallible;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
-use crate::Timestamps;
+use crate::mfa::TotpUri;
+use crate::user_management::UserId;
+use crate::{Timestamp, Timestamps};
 
 /// The ID of an [`AuthenticationFactor`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct AuthenticationFactorId(String);
 
 impl Display for AuthenticationFactorId {
@@ -26,6 +30,14 @@ impl From<&str> for AuthenticationFactorId {
     }
 }
 
+impl FromStr for AuthenticationFactorId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
 /// [WorkOS Docs: Authentication Factor](https://workos.com/docs/reference/mfa/authentication-factor)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AuthenticationFactor {
@@ -36,36 +48,118 @@ pub struct AuthenticationFactor {
     #[serde(flatten)]
     pub r#type: AuthenticationFactorType,
 
+    /// The ID of the user the authentication factor belongs to.
+    ///
+    /// This is only present for factors enrolled through User Management.
+    pub user_id: Option<UserId>,
+
     /// The timestamps for the authentication factor.
     #[serde(flatten)]
     pub timestamps: Timestamps,
 }
 
+impl AuthenticationFactor {
+    /// Returns the [`FactorKind`] of the authentication factor, without the data
+    /// associated with its type.
+    pub fn factor_kind(&self) -> FactorKind {
+        match self.r#type {
+            AuthenticationFactorType::Totp { .. } => FactorKind::Totp,
+            AuthenticationFactorType::Sms { .. } => FactorKind::Sms,
+        }
+    }
+
+    /// Returns the timestamp indicating when the authentication factor was created.
+    pub fn created_at(&self) -> &Timestamp {
+        &self.timestamps.created_at
+    }
+
+    /// Returns the timestamp indicating when the authentication factor was last updated.
+    pub fn updated_at(&self) -> &Timestamp {
+        &self.timestamps.updated_at
+    }
+}
+
+/// The kind of an [`AuthenticationFactor`], as returned by
+/// [`AuthenticationFactor::factor_kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FactorKind {
+    /// Time-based one-time password (TOTP).
+    Totp,
+
+    /// SMS.
+    Sms,
+}
+
+impl FactorKind {
+    /// Returns the string used to represent the factor kind in the WorkOS API, such as
+    /// `"totp"`.
+    pub fn as_api_str(&self) -> &'static str {
+        match self {
+            Self::Totp => "totp",
+            Self::Sms => "sms",
+        }
+    }
+}
+
+impl Display for FactorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_api_str())
+    }
+}
+
 /// The type of an [`AuthenticationFactor`].
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 #[serde(rename_all = "snake_case")]
 pub enum AuthenticationFactorType {
     /// Time-based one-time password (TOTP).
     Totp {
+        /// The issuer shown in the authenticator app.
+        issuer: Option<String>,
+
+        /// The user shown in the authenticator app.
+        user: Option<String>,
+
         /// A [data URL](https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/Data_URLs)
         /// containing the scannable QR code to enroll the factor.
-        qr_code: String,
+        ///
+        /// This is only present when the factor is first enrolled.
+        qr_code: Option<String>,
 
         /// The TOTP secret.
         ///
         /// This can be manually entered into some authenticator apps in place of scanning the `qr_code`.
-        secret: String,
+        ///
+        /// This is only present when the factor is first enrolled.
+        secret: Option<String>,
 
         /// The `otpauth://` URI that is encoded in the `qr_code`.
-        uri: String,
+        ///
+        /// This is only present when the factor is first enrolled.
+        uri: Option<String>,
     },
-    ///
+
+    /// SMS.
     Sms {
         /// The phone number the factor was enrolled with.
         phone_number: String,
     },
 }
 
+impl AuthenticationFactorType {
+    /// Parses the `otpauth://` URI of a TOTP factor into its components.
+    ///
+    /// Returns [`None`] if the factor is not a TOTP factor, if the URI is not present,
+    /// or if it is not a valid TOTP URI.
+    pub fn parse_uri(&self) -> Option<TotpUri> {
+        match self {
+            Self::Totp { uri: Some(uri), .. } => TotpUri::parse(uri),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use serde_json::json;
@@ -94,10 +188,13 @@ mod test {
             AuthenticationFactor {
                 id: AuthenticationFactorId::from("auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ"),
                 r#type: AuthenticationFactorType::Totp {
-                    qr_code: "data:image/png;base64,{base64EncodedPng}".to_string(),
-                    secret: "NAGCCFS3EYRB422HNAKAKY3XDUORMSRF".to_string(),
-                    uri: "otpauth://totp/FooCorp:alan.turing@foo-corp.com?secret=NAGCCFS3EYRB422HNAKAKY3XDUORMSRF&issuer=FooCorp".to_string()
+                    issuer: None,
+                    user: None,
+                    qr_code: Some("data:image/png;base64,{base64EncodedPng}".to_string()),
+                    secret: Some("NAGCCFS3EYRB422HNAKAKY3XDUORMSRF".to_string()),
+                    uri: Some("otpauth://totp/FooCorp:alan.turing@foo-corp.com?secret=NAGCCFS3EYRB422HNAKAKY3XDUORMSRF&issuer=FooCorp".to_string())
                 },
+                user_id: None,
                 timestamps: Timestamps {
                     created_at: Timestamp::try_from("2022-02-15T15:14:19.392Z").unwrap(),
                     updated_at: Timestamp::try_from("2022-02-15T15:14:19.392Z").unwrap(),
@@ -130,6 +227,7 @@ mod test {
                 r#type: AuthenticationFactorType::Sms {
                     phone_number: "+15005550006".to_string()
                 },
+                user_id: None,
                 timestamps: Timestamps {
                     created_at: Timestamp::try_from("2022-02-15T15:14:19.392Z").unwrap(),
                     updated_at: Timestamp::try_from("2022-02-15T15:14:19.392Z").unwrap(),
@@ -137,4 +235,97 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn it_deserializes_the_user_a_factor_belongs_to() {
+        let factor: AuthenticationFactor = serde_json::from_str(
+            &json!({
+              "object": "authentication_factor",
+              "id": "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ",
+              "created_at": "2022-02-15T15:14:19.392Z",
+              "updated_at": "2022-02-15T15:14:19.392Z",
+              "type": "totp",
+              "totp": {
+                  "issuer": "Foo Corp",
+                  "user": "alan.turing@foo-corp.com"
+              },
+              "user_id": "user_01E4ZCR3C56J083X43JQXF3JK5"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            factor.user_id,
+            Some(UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"))
+        )
+    }
+
+    #[test]
+    fn it_parses_the_uri_of_a_totp_factor() {
+        let factor_type = AuthenticationFactorType::Totp {
+            issuer: None,
+            user: None,
+            qr_code: None,
+            secret: Some("NAGCCFS3EYRB422HNAKAKY3XDUORMSRF".to_string()),
+            uri: Some("otpauth://totp/FooCorp:alan.turing@foo-corp.com?secret=NAGCCFS3EYRB422HNAKAKY3XDUORMSRF&issuer=FooCorp".to_string()),
+        };
+
+        assert_eq!(
+            factor_type.parse_uri(),
+            Some(TotpUri {
+                issuer: Some("FooCorp".to_string()),
+                account: "alan.turing@foo-corp.com".to_string(),
+                secret: "NAGCCFS3EYRB422HNAKAKY3XDUORMSRF".to_string(),
+                algorithm: "SHA1".to_string(),
+                digits: 6,
+                period: 30,
+            })
+        )
+    }
+
+    #[test]
+    fn it_does_not_parse_a_uri_for_an_sms_factor() {
+        let factor_type = AuthenticationFactorType::Sms {
+            phone_number: "+15005550006".to_string(),
+        };
+
+        assert_eq!(factor_type.parse_uri(), None)
+    }
+
+    fn factor(r#type: AuthenticationFactorType) -> AuthenticationFactor {
+        AuthenticationFactor {
+            id: AuthenticationFactorId::from("auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ"),
+            r#type,
+            user_id: None,
+            timestamps: Timestamps {
+                created_at: Timestamp::try_from("2022-02-15T15:14:19.392Z").unwrap(),
+                updated_at: Timestamp::try_from("2022-02-15T15:14:19.392Z").unwrap(),
+            },
+        }
+    }
+
+    #[test]
+    fn it_returns_the_kind_of_a_totp_factor() {
+        let factor = factor(AuthenticationFactorType::Totp {
+            issuer: Some("Foo Corp".to_string()),
+            user: Some("alan.turing@foo-corp.com".to_string()),
+            qr_code: None,
+            secret: None,
+            uri: None,
+        });
+
+        assert_eq!(factor.factor_kind(), FactorKind::Totp);
+        assert_eq!(factor.factor_kind().to_string(), "totp")
+    }
+
+    #[test]
+    fn it_returns_the_kind_of_an_sms_factor() {
+        let factor = factor(AuthenticationFactorType::Sms {
+            phone_number: "+15005550006".to_string(),
+        });
+
+        assert_eq!(factor.factor_kind(), FactorKind::Sms);
+        assert_eq!(factor.factor_kind().to_string(), "sms")
+    }
 }
@@ -0,0 +1,115 @@
+use url::Url;
+
+/// The components of an `otpauth://totp/` URI, as described by the
+/// [Key Uri Format](https://github.com/google/google-authenticator/wiki/Key-Uri-Format).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TotpUri {
+    /// The issuer of the account, such as the name of the application.
+    pub issuer: Option<String>,
+
+    /// The name of the account, typically the user's email address.
+    pub account: String,
+
+    /// The base32-encoded TOTP secret.
+    pub secret: String,
+
+    /// The HMAC algorithm used to generate codes, such as `SHA1`.
+    pub algorithm: String,
+
+    /// The number of digits in each code.
+    pub digits: u32,
+
+    /// The number of seconds each code is valid for.
+    pub period: u64,
+}
+
+impl TotpUri {
+    /// Parses an `otpauth://totp/` URI, returning [`None`] if it is not a valid TOTP URI.
+    pub fn parse(uri: &str) -> Option<Self> {
+        let url = Url::parse(uri).ok()?;
+        if url.scheme() != "otpauth" || url.host_str() != Some("totp") {
+            return None;
+        }
+
+        let label = percent_encoding::percent_decode_str(url.path().trim_start_matches('/'))
+            .decode_utf8()
+            .ok()?;
+        let (label_issuer, account) = match label.split_once(':') {
+            Some((issuer, account)) => (Some(issuer.to_string()), account.trim().to_string()),
+            None => (None, label.to_string()),
+        };
+
+        let mut secret = None;
+        let mut issuer = None;
+        let mut algorithm = None;
+        let mut digits = None;
+        let mut period = None;
+
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                "secret" => secret = Some(value.into_owned()),
+                "issuer" => issuer = Some(value.into_owned()),
+                "algorithm" => algorithm = Some(value.into_owned()),
+                "digits" => digits = Some(value.parse().ok()?),
+                "period" => period = Some(value.parse().ok()?),
+                _ => {}
+            }
+        }
+
+        Some(Self {
+            issuer: issuer.or(label_issuer),
+            account,
+            secret: secret?,
+            algorithm: algorithm.unwrap_or_else(|| "SHA1".to_string()),
+            digits: digits.unwrap_or(6),
+            period: period.unwrap_or(30),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TotpUri;
+
+    #[test]
+    fn it_applies_the_default_parameters() {
+        assert_eq!(
+            TotpUri::parse("otpauth://totp/alan.turing%40foo-corp.com?secret=JBSWY3DPEHPK3PXP"),
+            Some(TotpUri {
+                issuer: None,
+                account: "alan.turing@foo-corp.com".to_string(),
+                secret: "JBSWY3DPEHPK3PXP".to_string(),
+                algorithm: "SHA1".to_string(),
+                digits: 6,
+                period: 30,
+            })
+        )
+    }
+
+    #[test]
+    fn it_parses_the_explicit_parameters() {
+        assert_eq!(
+            TotpUri::parse(
+                "otpauth://totp/Foo%20Corp:alan.turing@foo-corp.com?secret=JBSWY3DPEHPK3PXP&algorithm=SHA256&digits=8&period=60"
+            ),
+            Some(TotpUri {
+                issuer: Some("Foo Corp".to_string()),
+                account: "alan.turing@foo-corp.com".to_string(),
+                secret: "JBSWY3DPEHPK3PXP".to_string(),
+                algorithm: "SHA256".to_string(),
+                digits: 8,
+                period: 60,
+            })
+        )
+    }
+
+    #[test]
+    fn it_rejects_invalid_uris() {
+        assert_eq!(TotpUri::parse("not a uri"), None);
+        assert_eq!(
+            TotpUri::parse("otpauth://hotp/Foo:bar?secret=JBSWY3DPEHPK3PXP&counter=0"),
+            None
+        );
+        assert_eq!(TotpUri::parse("otpauth://totp/Foo:bar"), None);
+    }
+}
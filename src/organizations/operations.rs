@@ -1,11 +1,19 @@
 mod create_organization;
+mod create_organization_domain;
 mod delete_organization;
 mod get_organization;
+mod get_organization_domain;
+mod list_organization_connections;
+mod list_organization_directory_users;
 mod list_organizations;
 mod update_organization;
 
 pub use create_organization::*;
+pub use create_organization_domain::*;
 pub use delete_organization::*;
 pub use get_organization::*;
+pub use get_organization_domain::*;
+pub use list_organization_connections::*;
+pub use list_organization_directory_users::*;
 pub use list_organizations::*;
 pub use update_organization::*;
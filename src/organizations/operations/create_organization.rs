@@ -5,7 +5,7 @@ use serde::Serialize;
 use thiserror::Error;
 
 use crate::organizations::{Organization, Organizations};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`CreateOrganization`].
 #[derive(Debug, Serialize)]
@@ -19,6 +19,9 @@ pub struct CreateOrganizationParams<'a> {
     ///
     /// See [here](https://workos.com/docs/sso/guide/frequently-asked-questions#allow-profiles-outside-organization)
     /// for more details.
+    ///
+    /// This setting has been deprecated by WorkOS in favor of domain verification,
+    /// and should be left as `None` for new organizations.
     pub allow_profiles_outside_organization: Option<&'a bool>,
 
     /// The domains of the organization.
@@ -27,9 +30,51 @@ pub struct CreateOrganizationParams<'a> {
     pub domains: HashSet<&'a str>,
 }
 
+impl<'a> CreateOrganizationParams<'a> {
+    /// Checks that the parameters are valid without calling the WorkOS API.
+    ///
+    /// At least one domain must be provided unless `allow_profiles_outside_organization`
+    /// is `true`. This is called by [`CreateOrganization::create_organization`] before
+    /// the request is sent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    ///
+    /// # use workos::organizations::*;
+    /// let params = CreateOrganizationParams {
+    ///     name: "Foo Corp",
+    ///     allow_profiles_outside_organization: None,
+    ///     domains: HashSet::new(),
+    /// };
+    ///
+    /// assert!(matches!(
+    ///     params.validate(),
+    ///     Err(CreateOrganizationError::MissingDomains)
+    /// ));
+    /// ```
+    pub fn validate(&self) -> Result<(), CreateOrganizationError> {
+        let allow_profiles_outside_organization =
+            self.allow_profiles_outside_organization == Some(&true);
+
+        if self.domains.is_empty() && !allow_profiles_outside_organization {
+            return Err(CreateOrganizationError::MissingDomains);
+        }
+
+        Ok(())
+    }
+}
+
 /// An error returned from [`CreateOrganization`].
 #[derive(Debug, Error)]
-pub enum CreateOrganizationError {}
+pub enum CreateOrganizationError {
+    /// No domains were provided and `allow_profiles_outside_organization` is not `true`.
+    #[error(
+        "at least one domain is required unless profiles outside the organization are allowed"
+    )]
+    MissingDomains,
+}
 
 impl From<CreateOrganizationError> for WorkOsError<CreateOrganizationError> {
     fn from(err: CreateOrganizationError) -> Self {
@@ -79,6 +124,8 @@ impl<'a> CreateOrganization for Organizations<'a> {
         &self,
         params: &CreateOrganizationParams<'_>,
     ) -> WorkOsResult<Organization, CreateOrganizationError> {
+        params.validate()?;
+
         let url = self.workos.base_url().join("/organizations")?;
         let organization = self
             .workos
@@ -86,10 +133,11 @@ impl<'a> CreateOrganization for Organizations<'a> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_instrumented(self.workos)
             .await?
-            .handle_unauthorized_or_generic_error()?
-            .json::<Organization>()
+            .handle_unauthorized_or_generic_error()
+            .await?
+            .parse_json::<Organization, _>()
             .await?;
 
         Ok(organization)
@@ -98,6 +146,7 @@ impl<'a> CreateOrganization for Organizations<'a> {
 
 #[cfg(test)]
 mod test {
+    use matches::assert_matches;
     use mockito::{self};
     use serde_json::json;
     use tokio;
@@ -154,4 +203,72 @@ mod test {
             OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")
         )
     }
+
+    #[tokio::test]
+    async fn it_returns_an_error_without_calling_the_api_when_no_domains_are_provided() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server.mock("POST", "/organizations").expect(0).create();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let result = workos
+            .organizations()
+            .create_organization(&CreateOrganizationParams {
+                name: "Foo Corp",
+                allow_profiles_outside_organization: None,
+                domains: HashSet::new(),
+            })
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(
+                CreateOrganizationError::MissingDomains
+            ))
+        );
+        mock.assert();
+    }
+
+    #[test]
+    fn it_accepts_params_with_domains() {
+        for allow_profiles_outside_organization in [None, Some(&false), Some(&true)] {
+            let params = CreateOrganizationParams {
+                name: "Foo Corp",
+                allow_profiles_outside_organization,
+                domains: HashSet::from(["foo-corp.com"]),
+            };
+
+            assert_matches!(params.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn it_accepts_params_without_domains_when_profiles_outside_the_organization_are_allowed() {
+        let params = CreateOrganizationParams {
+            name: "Foo Corp",
+            allow_profiles_outside_organization: Some(&true),
+            domains: HashSet::new(),
+        };
+
+        assert_matches!(params.validate(), Ok(()));
+    }
+
+    #[test]
+    fn it_rejects_params_without_domains_when_profiles_outside_the_organization_are_not_allowed() {
+        for allow_profiles_outside_organization in [None, Some(&false)] {
+            let params = CreateOrganizationParams {
+                name: "Foo Corp",
+                allow_profiles_outside_organization,
+                domains: HashSet::new(),
+            };
+
+            assert_matches!(
+                params.validate(),
+                Err(CreateOrganizationError::MissingDomains)
+            );
+        }
+    }
 }
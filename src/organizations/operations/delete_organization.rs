@@ -3,7 +3,7 @@ use serde::Serialize;
 use thiserror::Error;
 
 use crate::organizations::{OrganizationId, Organizations};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`DeleteOrganization`].
 #[derive(Debug, Serialize)]
@@ -68,9 +68,10 @@ impl<'a> DeleteOrganization for Organizations<'a> {
             .client()
             .delete(url)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_instrumented(self.workos)
             .await?
-            .handle_unauthorized_or_generic_error()?;
+            .handle_unauthorized_or_generic_error()
+            .await?;
 
         Ok(())
     }
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::organizations::{Organization, OrganizationId, Organizations};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetOrganization`].
 #[derive(Debug, Error)]
@@ -59,10 +59,11 @@ impl<'a> GetOrganization for Organizations<'a> {
             .client()
             .get(url)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_instrumented(self.workos)
             .await?
-            .handle_unauthorized_or_generic_error()?
-            .json::<Organization>()
+            .handle_unauthorized_or_generic_error()
+            .await?
+            .parse_json::<Organization, _>()
             .await?;
 
         Ok(organization)
@@ -71,7 +72,9 @@ impl<'a> GetOrganization for Organizations<'a> {
 
 #[cfg(test)]
 mod test {
+    use matches::assert_matches;
     use mockito::{self};
+    use reqwest::StatusCode;
     use serde_json::json;
     use tokio;
 
@@ -127,4 +130,55 @@ mod test {
             OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")
         )
     }
+
+    #[tokio::test]
+    async fn it_returns_a_request_error_when_the_organization_is_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(404)
+            .create();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let result = workos
+            .organizations()
+            .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .await;
+
+        match result {
+            Err(WorkOsError::Operation(err)) => match err {},
+            result => assert_matches!(
+                result,
+                Err(WorkOsError::RequestError(err)) if err.status() == Some(StatusCode::NOT_FOUND)
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_empty_body_error_when_the_response_is_empty() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(200)
+            .create();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let result = workos
+            .organizations()
+            .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::EmptyBody { context }) if context.ends_with("Organization")
+        );
+    }
 }
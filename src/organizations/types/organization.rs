@@ -1,11 +1,14 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
-use crate::Timestamps;
+use crate::{KnownOrUnknown, Timestamp, Timestamps};
 
 /// The ID of an [`Organization`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct OrganizationId(String);
 
 impl Display for OrganizationId {
@@ -26,12 +29,26 @@ impl From<&str> for OrganizationId {
     }
 }
 
+impl FromStr for OrganizationId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
 /// [WorkOS Docs: Organization](https://workos.com/docs/reference/organization)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Organization {
     /// The ID of the organization.
     pub id: OrganizationId,
 
+    /// The type of the object, which is always `"organization"` for organizations.
+    ///
+    /// This is empty if the payload did not include it.
+    #[serde(default)]
+    pub object: String,
+
     /// The name of the organization.
     pub name: String,
 
@@ -41,18 +58,43 @@ pub struct Organization {
     ///
     /// See [here](https://workos.com/docs/sso/guide/frequently-asked-questions#allow-profiles-outside-organization)
     /// for more details.
+    ///
+    /// This setting has been deprecated by WorkOS in favor of domain verification.
+    /// Instead of allowing profiles from any domain, add each domain to the
+    /// organization and check its [`OrganizationDomain::state`].
+    #[deprecated(note = "use the `state` of the organization's `domains` instead")]
+    #[serde(default)]
     pub allow_profiles_outside_organization: bool,
 
     /// The list of user email domains for the organization.
     pub domains: Vec<OrganizationDomain>,
 
+    /// The key-value metadata attached to the organization.
+    ///
+    /// This can be used to store additional information about the organization,
+    /// such as the ID of the corresponding customer in a billing system.
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+
     /// The timestamps for the organization.
     #[serde(flatten)]
     pub timestamps: Timestamps,
 }
 
+impl Organization {
+    /// Returns the timestamp indicating when the organization was created.
+    pub fn created_at(&self) -> &Timestamp {
+        &self.timestamps.created_at
+    }
+
+    /// Returns the timestamp indicating when the organization was last updated.
+    pub fn updated_at(&self) -> &Timestamp {
+        &self.timestamps.updated_at
+    }
+}
+
 /// The ID of an [`OrganizationDomain`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct OrganizationDomainId(String);
 
 impl Display for OrganizationDomainId {
@@ -73,6 +115,14 @@ impl From<&str> for OrganizationDomainId {
     }
 }
 
+impl FromStr for OrganizationDomainId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
 /// [WorkOS Docs: Organization Domain](https://workos.com/docs/reference/organization-domain)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OrganizationDomain {
@@ -81,4 +131,161 @@ pub struct OrganizationDomain {
 
     /// The domain.
     pub domain: String,
+
+    /// The verification state of the domain.
+    pub state: Option<KnownOrUnknown<OrganizationDomainState, String>>,
+
+    /// The ID of the organization the domain belongs to.
+    pub organization_id: Option<OrganizationId>,
+
+    /// The strategy used to verify the domain.
+    pub verification_strategy:
+        Option<KnownOrUnknown<OrganizationDomainVerificationStrategy, String>>,
+
+    /// The token used to verify the domain using a DNS TXT record.
+    pub verification_token: Option<String>,
+}
+
+/// The verification state of an [`OrganizationDomain`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationDomainState {
+    /// The domain is pending verification.
+    Pending,
+
+    /// The domain has been verified.
+    Verified,
+
+    /// Verification of the domain failed.
+    Failed,
+
+    /// The domain was verified before domain verification was introduced.
+    LegacyVerified,
+}
+
+/// The strategy used to verify an [`OrganizationDomain`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationDomainVerificationStrategy {
+    /// The domain is verified by adding a DNS TXT record containing the
+    /// verification token.
+    Dns,
+
+    /// The domain was verified manually.
+    Manual,
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    #[allow(deprecated)]
+    fn it_deserializes_an_organization_with_allow_profiles_outside_organization() {
+        let organization: Organization = serde_json::from_str(
+            &json!({
+              "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+              "object": "organization",
+              "name": "Foo Corp",
+              "allow_profiles_outside_organization": true,
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+              "domains": [
+                {
+                  "domain": "foo-corp.com",
+                  "id": "org_domain_01EHZNVPK2QXHMVWCEDQEKY69A",
+                  "object": "organization_domain"
+                }
+              ]
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert!(organization.allow_profiles_outside_organization);
+        assert_eq!(organization.domains[0].state, None)
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn it_deserializes_an_organization_with_domain_states() {
+        let organization: Organization = serde_json::from_str(
+            &json!({
+              "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+              "object": "organization",
+              "name": "Foo Corp",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+              "domains": [
+                {
+                  "domain": "foo-corp.com",
+                  "id": "org_domain_01EHZNVPK2QXHMVWCEDQEKY69A",
+                  "object": "organization_domain",
+                  "state": "verified"
+                },
+                {
+                  "domain": "another-foo-corp-domain.com",
+                  "id": "org_domain_01EHZNS0H9W90A90FV79GAB6AB",
+                  "object": "organization_domain",
+                  "state": "pending"
+                }
+              ]
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert!(!organization.allow_profiles_outside_organization);
+        assert_eq!(
+            organization
+                .domains
+                .into_iter()
+                .map(|domain| domain.state)
+                .collect::<Vec<_>>(),
+            vec![
+                Some(KnownOrUnknown::Known(OrganizationDomainState::Verified)),
+                Some(KnownOrUnknown::Known(OrganizationDomainState::Pending))
+            ]
+        )
+    }
+
+    #[test]
+    fn it_captures_the_object_type() {
+        let organization: Organization = serde_json::from_value(json!({
+          "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+          "object": "organization",
+          "name": "Foo Corp",
+          "created_at": "2021-06-25T19:07:33.155Z",
+          "updated_at": "2021-06-25T19:07:33.155Z",
+          "domains": []
+        }))
+        .unwrap();
+
+        assert_eq!(organization.object, "organization")
+    }
+
+    #[test]
+    fn it_defaults_the_object_type_when_it_is_omitted() {
+        let organization: Organization = serde_json::from_value(json!({
+          "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+          "name": "Foo Corp",
+          "created_at": "2021-06-25T19:07:33.155Z",
+          "updated_at": "2021-06-25T19:07:33.155Z",
+          "domains": []
+        }))
+        .unwrap();
+
+        assert_eq!(organization.object, "")
+    }
+
+    #[test]
+    fn it_parses_an_organization_id() {
+        let id: OrganizationId = "org_01EHZNVPK3SFK441A1RGBFSHRT".parse().unwrap();
+
+        assert_eq!(id, OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+    }
 }
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use serde::Serialize;
 
 use crate::passwordless::{Passwordless, PasswordlessSessionId};
-use crate::{ResponseExt, WorkOsResult};
+use crate::{RequestExt, ResponseExt, WorkOsResult};
 
 /// The parameters for [`SendPasswordlessSession`].
 #[derive(Debug, Serialize)]
@@ -62,9 +62,10 @@ impl<'a> SendPasswordlessSession for Passwordless<'a> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_instrumented(self.workos)
             .await?
-            .handle_unauthorized_or_generic_error()?;
+            .handle_unauthorized_or_generic_error()
+            .await?;
 
         Ok(())
     }
@@ -1,11 +1,13 @@
+use std::convert::Infallible;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
 use crate::Timestamp;
 
 /// The ID of an [`PasswordlessSession`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct PasswordlessSessionId(String);
 
 impl Display for PasswordlessSessionId {
@@ -26,6 +28,14 @@ impl From<&str> for PasswordlessSessionId {
     }
 }
 
+impl FromStr for PasswordlessSessionId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
 /// The type of a [`PasswordlessSession`].
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
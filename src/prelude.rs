@@ -0,0 +1,110 @@
+//! A prelude that brings the operation traits and commonly used types into scope.
+//!
+//! Each WorkOS operation is implemented as a trait, so its trait must be in scope
+//! before the operation can be called. Importing the prelude brings every operation
+//! into scope at once.
+//!
+//! # Examples
+//!
+//! ```
+//! use workos::prelude::*;
+//! use workos::organizations::ListOrganizationsParams;
+//! use workos::sso::ListConnectionsParams;
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+//!
+//! let organizations = workos
+//!     .organizations()
+//!     .list_organizations(&ListOrganizationsParams::default())
+//!     .await?;
+//!
+//! let connections = workos
+//!     .sso()
+//!     .list_connections(&ListConnectionsParams::default())
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::admin_portal::GeneratePortalLink;
+pub use crate::directory_sync::{
+    DeleteDirectory, GetDirectory, GetDirectoryGroup, GetDirectoryUser, ListDirectories,
+    ListDirectoryGroups, ListDirectoryUsers,
+};
+pub use crate::mfa::{
+    ChallengeFactor, EnrollAndChallenge, EnrollFactor, GetFactor, ReChallenge, VerifyChallenge,
+};
+pub use crate::organizations::{
+    CreateOrganization, CreateOrganizationDomain, DeleteOrganization, GetOrganization,
+    GetOrganizationDomain, ListOrganizationConnections, ListOrganizationDirectoryUsers,
+    ListOrganizations, UpdateOrganization,
+};
+pub use crate::passwordless::{CreatePasswordlessSession, SendPasswordlessSession};
+pub use crate::sso::{
+    DeleteConnection, FindConnectionForOrganization, FindConnectionForOrganizationByType,
+    GetAuthorizationUrl, GetConnection, GetProfile, GetProfileAndToken, GetProfileForOrganization,
+    ListConnections,
+};
+pub use crate::user_management::{
+    AuthenticateWithCode, CreateOrganizationMembership, DeactivateOrganizationMembership, GetUser,
+    ListAuthFactors, ListOrganizationMemberships, ReactivateOrganizationMembership,
+    UpdateOrganizationMembership,
+};
+pub use crate::{
+    ApiKey, ClientId, ClientSecret, KnownOrUnknown, PaginatedList, PaginationOrder,
+    PaginationParams, WorkOs, WorkOsError, WorkOsResult,
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_object_safe<T: ?Sized>() {}
+
+    #[test]
+    fn the_operation_traits_are_object_safe() {
+        assert_object_safe::<dyn GeneratePortalLink>();
+        assert_object_safe::<dyn DeleteDirectory>();
+        assert_object_safe::<dyn GetDirectory>();
+        assert_object_safe::<dyn GetDirectoryGroup>();
+        assert_object_safe::<dyn GetDirectoryUser>();
+        assert_object_safe::<dyn ListDirectories>();
+        assert_object_safe::<dyn ListDirectoryGroups>();
+        assert_object_safe::<dyn ListDirectoryUsers>();
+        assert_object_safe::<dyn ChallengeFactor>();
+        assert_object_safe::<dyn EnrollAndChallenge>();
+        assert_object_safe::<dyn EnrollFactor>();
+        assert_object_safe::<dyn GetFactor>();
+        assert_object_safe::<dyn ReChallenge>();
+        assert_object_safe::<dyn VerifyChallenge>();
+        assert_object_safe::<dyn CreateOrganization>();
+        assert_object_safe::<dyn CreateOrganizationDomain>();
+        assert_object_safe::<dyn DeleteOrganization>();
+        assert_object_safe::<dyn GetOrganization>();
+        assert_object_safe::<dyn GetOrganizationDomain>();
+        assert_object_safe::<dyn ListOrganizationConnections>();
+        assert_object_safe::<dyn ListOrganizationDirectoryUsers>();
+        assert_object_safe::<dyn ListOrganizations>();
+        assert_object_safe::<dyn UpdateOrganization>();
+        assert_object_safe::<dyn CreatePasswordlessSession>();
+        assert_object_safe::<dyn SendPasswordlessSession>();
+        assert_object_safe::<dyn DeleteConnection>();
+        assert_object_safe::<dyn FindConnectionForOrganization>();
+        assert_object_safe::<dyn FindConnectionForOrganizationByType>();
+        assert_object_safe::<dyn GetAuthorizationUrl>();
+        assert_object_safe::<dyn GetConnection>();
+        assert_object_safe::<dyn GetProfile>();
+        assert_object_safe::<dyn GetProfileAndToken>();
+        assert_object_safe::<dyn GetProfileForOrganization>();
+        assert_object_safe::<dyn ListConnections>();
+        assert_object_safe::<dyn AuthenticateWithCode>();
+        assert_object_safe::<dyn CreateOrganizationMembership>();
+        assert_object_safe::<dyn DeactivateOrganizationMembership>();
+        assert_object_safe::<dyn GetUser>();
+        assert_object_safe::<dyn ListAuthFactors>();
+        assert_object_safe::<dyn ListOrganizationMemberships>();
+        assert_object_safe::<dyn ReactivateOrganizationMembership>();
+        assert_object_safe::<dyn UpdateOrganizationMembership>();
+    }
+}
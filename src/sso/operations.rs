@@ -1,13 +1,19 @@
 mod delete_connection;
+mod find_connection_for_organization;
+mod find_connection_for_organization_by_type;
 mod get_authorization_url;
 mod get_connection;
 mod get_profile;
 mod get_profile_and_token;
+mod get_profile_for_organization;
 mod list_connections;
 
 pub use delete_connection::*;
+pub use find_connection_for_organization::*;
+pub use find_connection_for_organization_by_type::*;
 pub use get_authorization_url::*;
 pub use get_connection::*;
 pub use get_profile::*;
 pub use get_profile_and_token::*;
+pub use get_profile_for_organization::*;
 pub use list_connections::*;
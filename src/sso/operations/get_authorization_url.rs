@@ -1,3 +1,4 @@
+use thiserror::Error;
 use url::{ParseError, Url};
 
 use crate::organizations::OrganizationId;
@@ -6,6 +7,7 @@ use crate::ClientId;
 
 /// An OAuth provider to use for Single Sign-On (SSO).
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Provider {
     /// Sign in with Authkit.
     Authkit,
@@ -36,18 +38,42 @@ pub struct GetAuthorizationUrlParams<'a> {
     /// The client ID for the environment in which SSO is being initiated.
     ///
     /// This value can be obtained from the "Configuration" page in the WorkOS Dashboard.
-    pub client_id: &'a ClientId,
+    ///
+    /// Defaults to the client ID configured on the [`WorkOs`](crate::WorkOs) client if
+    /// not provided.
+    pub client_id: Option<&'a ClientId>,
 
     /// The redirect URI the user will be redirected to after successfully signing in.
-    pub redirect_uri: &'a str,
+    ///
+    /// Defaults to the redirect URI configured on the [`WorkOs`](crate::WorkOs) client
+    /// if not provided.
+    pub redirect_uri: Option<&'a str>,
 
     /// The connection selector to use to initiate SSO.
     pub connection_selector: ConnectionSelector<'a>,
 
     /// The state parameter that will be passed back to the redirect URI.
+    ///
+    /// Structured state can be passed using [`EncodedState`](crate::EncodedState).
     pub state: Option<&'a str>,
 }
 
+/// An error returned from [`GetAuthorizationUrl`].
+#[derive(Debug, Error)]
+pub enum GetAuthorizationUrlError {
+    /// No client ID was provided and the client does not have a default client ID.
+    #[error("no client ID was provided")]
+    MissingClientId,
+
+    /// No redirect URI was provided and the client does not have a default redirect URI.
+    #[error("no redirect URI was provided")]
+    MissingRedirectUri,
+
+    /// An error occurred while parsing a URL.
+    #[error("URL parse error")]
+    UrlParseError(#[from] ParseError),
+}
+
 /// [WorkOS Docs: Get Authorization URL](https://workos.com/docs/reference/sso/authorize/get)
 pub trait GetAuthorizationUrl {
     /// Returns an authorization URL to use to initiate SSO.
@@ -57,18 +83,17 @@ pub trait GetAuthorizationUrl {
     /// # Examples
     ///
     /// ```
-    /// # use url::ParseError;
     /// # use workos::sso::*;
     /// use workos::{ApiKey, ClientId, WorkOs};
     ///
-    /// # fn run() -> Result<(), ParseError> {
+    /// # fn run() -> Result<(), GetAuthorizationUrlError> {
     /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
     ///
     /// let authorization_url = workos
     ///     .sso()
     ///     .get_authorization_url(&GetAuthorizationUrlParams {
-    ///         client_id: &ClientId::from("client_123456789"),
-    ///         redirect_uri: "https://your-app.com/callback",
+    ///         client_id: Some(&ClientId::from("client_123456789")),
+    ///         redirect_uri: Some("https://your-app.com/callback"),
     ///         connection_selector: ConnectionSelector::Connection(&ConnectionId::from(
     ///             "conn_01E4ZCR3C56J083X43JQXF3JK5",
     ///         )),
@@ -78,11 +103,17 @@ pub trait GetAuthorizationUrl {
     /// # }
     /// # run().unwrap();
     /// ```
-    fn get_authorization_url(&self, params: &GetAuthorizationUrlParams) -> Result<Url, ParseError>;
+    fn get_authorization_url(
+        &self,
+        params: &GetAuthorizationUrlParams,
+    ) -> Result<Url, GetAuthorizationUrlError>;
 }
 
 impl<'a> GetAuthorizationUrl for Sso<'a> {
-    fn get_authorization_url(&self, params: &GetAuthorizationUrlParams) -> Result<Url, ParseError> {
+    fn get_authorization_url(
+        &self,
+        params: &GetAuthorizationUrlParams,
+    ) -> Result<Url, GetAuthorizationUrlError> {
         let GetAuthorizationUrlParams {
             connection_selector,
             client_id,
@@ -90,6 +121,14 @@ impl<'a> GetAuthorizationUrl for Sso<'a> {
             state,
         } = params;
 
+        let client_id = client_id
+            .or(self.workos.client_id())
+            .ok_or(GetAuthorizationUrlError::MissingClientId)?;
+
+        let redirect_uri = redirect_uri
+            .or(self.workos.redirect_uri())
+            .ok_or(GetAuthorizationUrlError::MissingRedirectUri)?;
+
         let query = {
             let client_id = client_id.to_string();
 
@@ -123,15 +162,18 @@ impl<'a> GetAuthorizationUrl for Sso<'a> {
             String::from(querystring::stringify(query_params).trim_end_matches('&'))
         };
 
-        self.workos
+        Ok(self
+            .workos
             .base_url()
-            .join(&format!("/sso/authorize?{}", query))
+            .join(&format!("/sso/authorize?{}", query))?)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{ApiKey, WorkOs};
+    use matches::assert_matches;
+
+    use crate::{ApiKey, EncodedState, WorkOs};
 
     use super::*;
 
@@ -142,8 +184,8 @@ mod test {
         let authorization_url = workos
             .sso()
             .get_authorization_url(&GetAuthorizationUrlParams {
-                client_id: &ClientId::from("client_123456789"),
-                redirect_uri: "https://your-app.com/callback",
+                client_id: Some(&ClientId::from("client_123456789")),
+                redirect_uri: Some("https://your-app.com/callback"),
                 connection_selector: ConnectionSelector::Connection(&ConnectionId::from(
                     "conn_1234",
                 )),
@@ -167,8 +209,8 @@ mod test {
 
         let authorization_url = workos_sso
             .get_authorization_url(&GetAuthorizationUrlParams {
-                client_id: &ClientId::from("client_123456789"),
-                redirect_uri: "https://your-app.com/callback",
+                client_id: Some(&ClientId::from("client_123456789")),
+                redirect_uri: Some("https://your-app.com/callback"),
                 connection_selector: ConnectionSelector::Organization(&OrganizationId::from(
                     "org_1234",
                 )),
@@ -192,8 +234,8 @@ mod test {
 
         let authorization_url = workos_sso
             .get_authorization_url(&GetAuthorizationUrlParams {
-                client_id: &ClientId::from("client_123456789"),
-                redirect_uri: "https://your-app.com/callback",
+                client_id: Some(&ClientId::from("client_123456789")),
+                redirect_uri: Some("https://your-app.com/callback"),
                 connection_selector: ConnectionSelector::Provider(&Provider::GoogleOauth),
                 state: None,
             })
@@ -207,4 +249,131 @@ mod test {
             .unwrap()
         )
     }
+
+    #[test]
+    fn it_uses_the_client_id_configured_on_the_client() {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .client_id(&ClientId::from("client_123456789"))
+            .build();
+
+        let authorization_url = workos
+            .sso()
+            .get_authorization_url(&GetAuthorizationUrlParams {
+                client_id: None,
+                redirect_uri: Some("https://your-app.com/callback"),
+                connection_selector: ConnectionSelector::Connection(&ConnectionId::from(
+                    "conn_1234",
+                )),
+                state: None,
+            })
+            .unwrap();
+
+        assert_eq!(
+            authorization_url,
+            Url::parse(
+                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https://your-app.com/callback&connection=conn_1234"
+            )
+            .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_when_no_client_id_is_available() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let result = workos
+            .sso()
+            .get_authorization_url(&GetAuthorizationUrlParams {
+                client_id: None,
+                redirect_uri: Some("https://your-app.com/callback"),
+                connection_selector: ConnectionSelector::Connection(&ConnectionId::from(
+                    "conn_1234",
+                )),
+                state: None,
+            });
+
+        assert_matches!(result, Err(GetAuthorizationUrlError::MissingClientId))
+    }
+
+    #[test]
+    fn it_round_trips_encoded_state_through_the_authorization_url() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct CallbackState {
+            csrf_token: String,
+            return_to: String,
+        }
+
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let state = CallbackState {
+            csrf_token: "f3a1b2c4".to_string(),
+            return_to: "/settings?tab=sso".to_string(),
+        };
+        let encoded_state = EncodedState::encode(&state).unwrap();
+
+        let authorization_url = workos
+            .sso()
+            .get_authorization_url(&GetAuthorizationUrlParams {
+                client_id: Some(&ClientId::from("client_123456789")),
+                redirect_uri: Some("https://your-app.com/callback"),
+                connection_selector: ConnectionSelector::Connection(&ConnectionId::from(
+                    "conn_1234",
+                )),
+                state: Some(encoded_state.as_str()),
+            })
+            .unwrap();
+
+        let returned_state = authorization_url
+            .query_pairs()
+            .find(|(key, _)| key == "state")
+            .map(|(_, value)| EncodedState::from(value.into_owned()))
+            .unwrap();
+
+        assert_eq!(returned_state.decode::<CallbackState>().unwrap(), state)
+    }
+
+    #[test]
+    fn it_uses_the_redirect_uri_configured_on_the_client() {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .redirect_uri("https://your-app.com/callback")
+            .build();
+
+        let authorization_url = workos
+            .sso()
+            .get_authorization_url(&GetAuthorizationUrlParams {
+                client_id: Some(&ClientId::from("client_123456789")),
+                redirect_uri: None,
+                connection_selector: ConnectionSelector::Connection(&ConnectionId::from(
+                    "conn_1234",
+                )),
+                state: None,
+            })
+            .unwrap();
+
+        assert_eq!(
+            authorization_url,
+            Url::parse(
+                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https://your-app.com/callback&connection=conn_1234"
+            )
+            .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_when_no_redirect_uri_is_available() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let result = workos
+            .sso()
+            .get_authorization_url(&GetAuthorizationUrlParams {
+                client_id: Some(&ClientId::from("client_123456789")),
+                redirect_uri: None,
+                connection_selector: ConnectionSelector::Connection(&ConnectionId::from(
+                    "conn_1234",
+                )),
+                state: None,
+            });
+
+        assert_matches!(result, Err(GetAuthorizationUrlError::MissingRedirectUri))
+    }
 }
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::sso::{Connection, ConnectionId, Sso};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetConnection`].
 #[derive(Debug, Error)]
@@ -59,10 +59,11 @@ impl<'a> GetConnection for Sso<'a> {
             .client()
             .get(url)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_instrumented(self.workos)
             .await?
-            .handle_unauthorized_or_generic_error()?
-            .json::<Connection>()
+            .handle_unauthorized_or_generic_error()
+            .await?
+            .parse_json::<Connection, _>()
             .await?;
 
         Ok(connection)
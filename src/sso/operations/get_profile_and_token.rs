@@ -4,7 +4,7 @@ use serde::Deserialize;
 use thiserror::Error;
 
 use crate::sso::{AccessToken, Profile, Sso};
-use crate::{AuthorizationCode, ClientId, WorkOsError, WorkOsResult};
+use crate::{AuthorizationCode, ClientId, RequestExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`GetProfileAndToken`].
 #[derive(Debug)]
@@ -57,7 +57,7 @@ impl HandleGetProfileAndTokenError for Response {
             Ok(_) => Ok(self),
             Err(err) => match err.status() {
                 Some(StatusCode::BAD_REQUEST) => {
-                    let error = self.json::<GetProfileAndTokenError>().await?;
+                    let error = self.parse_json::<GetProfileAndTokenError, _>().await?;
 
                     Err(match error.error.as_str() {
                         "invalid_client" | "unauthorized_client" => WorkOsError::Unauthorized,
@@ -121,11 +121,11 @@ impl<'a> GetProfileAndToken for Sso<'a> {
             .client()
             .post(url)
             .form(&params)
-            .send()
+            .send_instrumented(self.workos)
             .await?
             .handle_get_profile_and_token_error()
             .await?
-            .json::<GetProfileAndTokenResponse>()
+            .parse_json::<GetProfileAndTokenResponse, _>()
             .await?;
 
         Ok(get_profile_and_token_response)
@@ -1,9 +1,15 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use serde::Serialize;
+use thiserror::Error;
 
 use crate::organizations::OrganizationId;
 use crate::sso::{Connection, ConnectionType, Sso};
-use crate::{KnownOrUnknown, PaginatedList, PaginationParams, ResponseExt, WorkOsResult};
+use crate::{
+    KnownOrUnknown, PaginatedList, PaginationParams, RequestExt, ResponseExt, WorkOsError,
+    WorkOsResult,
+};
 
 /// The parameters for [`ListConnections`].
 #[derive(Debug, Default, Serialize)]
@@ -18,6 +24,22 @@ pub struct ListConnectionsParams<'a> {
     /// The type of connections to list.
     #[serde(rename = "connection_type")]
     pub r#type: Option<KnownOrUnknown<&'a ConnectionType, &'a str>>,
+
+    /// Additional query parameters to include when listing connections.
+    ///
+    /// This can be used to pass filters that are not yet supported by the SDK.
+    #[serde(flatten)]
+    pub extra: HashMap<String, String>,
+}
+
+/// An error returned from [`ListConnections`].
+#[derive(Debug, Error)]
+pub enum ListConnectionsError {}
+
+impl From<ListConnectionsError> for WorkOsError<ListConnectionsError> {
+    fn from(err: ListConnectionsError) -> Self {
+        Self::Operation(err)
+    }
 }
 
 /// [WorkOS Docs: List Connections](https://workos.com/docs/reference/sso/connection/list)
@@ -34,7 +56,7 @@ pub trait ListConnections {
     /// # use workos::sso::*;
     /// use workos::{ApiKey, WorkOs};
     ///
-    /// # async fn run() -> WorkOsResult<(), ()> {
+    /// # async fn run() -> WorkOsResult<(), ListConnectionsError> {
     /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
     ///
     /// let paginated_connections = workos
@@ -49,7 +71,7 @@ pub trait ListConnections {
     async fn list_connections(
         &self,
         params: &ListConnectionsParams<'_>,
-    ) -> WorkOsResult<PaginatedList<Connection>, ()>;
+    ) -> WorkOsResult<PaginatedList<Connection>, ListConnectionsError>;
 }
 
 #[async_trait]
@@ -57,7 +79,7 @@ impl<'a> ListConnections for Sso<'a> {
     async fn list_connections(
         &self,
         params: &ListConnectionsParams<'_>,
-    ) -> WorkOsResult<PaginatedList<Connection>, ()> {
+    ) -> WorkOsResult<PaginatedList<Connection>, ListConnectionsError> {
         let url = self.workos.base_url().join("/connections")?;
         let connections = self
             .workos
@@ -65,10 +87,11 @@ impl<'a> ListConnections for Sso<'a> {
             .get(url)
             .query(&params)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_instrumented(self.workos)
+            .await?
+            .handle_unauthorized_or_generic_error()
             .await?
-            .handle_unauthorized_or_generic_error()?
-            .json::<PaginatedList<Connection>>()
+            .parse_json::<PaginatedList<Connection>, _>()
             .await?;
 
         Ok(connections)
@@ -134,7 +157,10 @@ mod test {
 
         let paginated_list = workos
             .sso()
-            .list_connections(&Default::default())
+            .list_connections(&ListConnectionsParams {
+                pagination: PaginationParams::desc(),
+                ..Default::default()
+            })
             .await
             .unwrap();
 
@@ -186,6 +212,7 @@ mod test {
         let paginated_list = workos
             .sso()
             .list_connections(&ListConnectionsParams {
+                pagination: PaginationParams::desc(),
                 r#type: Some(KnownOrUnknown::Known(&ConnectionType::OktaSaml)),
                 ..Default::default()
             })
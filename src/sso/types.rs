@@ -1,9 +1,13 @@
 mod access_token;
 mod connection;
+mod connection_protocol;
 mod connection_type;
 mod profile;
+mod sso_callback;
 
 pub use access_token::*;
 pub use connection::*;
+pub use connection_protocol::*;
 pub use connection_type::*;
 pub use profile::*;
+pub use sso_callback::*;
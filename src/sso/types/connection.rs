@@ -1,13 +1,16 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
 use crate::organizations::OrganizationId;
 use crate::sso::ConnectionType;
-use crate::{KnownOrUnknown, Timestamps};
+use crate::{KnownOrUnknown, Timestamp, Timestamps};
 
 /// The ID of a [`Connection`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct ConnectionId(String);
 
 impl Display for ConnectionId {
@@ -28,8 +31,17 @@ impl From<&str> for ConnectionId {
     }
 }
 
+impl FromStr for ConnectionId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
 /// The state of a [`Connection`].
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 #[serde(rename_all = "snake_case")]
 pub enum ConnectionState {
     /// The connection is active.
@@ -40,11 +52,21 @@ pub enum ConnectionState {
 }
 
 /// [WorkOS Docs: Connection](https://workos.com/docs/reference/sso/connection)
+///
+/// Connections compare equal only when all of their fields match. To look up or
+/// deduplicate connections by identity, key them by their [`ConnectionId`], for
+/// example in a `HashMap<ConnectionId, Connection>`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Connection {
     /// The ID of the connection.
     pub id: ConnectionId,
 
+    /// The type of the object, which is always `"connection"` for connections.
+    ///
+    /// This is empty if the payload did not include it.
+    #[serde(default)]
+    pub object: String,
+
     /// The ID of the associated [`Organization`](crate::organizations::Organization) for this connection.
     pub organization_id: Option<OrganizationId>,
 
@@ -58,20 +80,125 @@ pub struct Connection {
     /// The state of the connection.
     pub state: KnownOrUnknown<ConnectionState, String>,
 
+    /// The email domains that are allowed to sign in using the connection.
+    ///
+    /// The WorkOS API does not support updating a connection's domains; they are
+    /// configured through the WorkOS Dashboard or the Admin Portal.
+    #[serde(default)]
+    pub domains: Vec<ConnectionDomain>,
+
+    /// The mapping of profile attributes to the attribute names sent by the
+    /// identity provider, if WorkOS returned it.
+    ///
+    /// This is useful when debugging why a [`Profile`](crate::sso::Profile) is
+    /// missing an expected attribute.
+    #[serde(default)]
+    pub attribute_mapping: Option<HashMap<String, String>>,
+
     /// The timestamps for the connection.
     #[serde(flatten)]
     pub timestamps: Timestamps,
 }
 
+impl Connection {
+    /// Returns the primary domain of the connection, which is the first of its
+    /// [`domains`](Self::domains), if it has any.
+    pub fn primary_domain(&self) -> Option<&str> {
+        self.domains.first().map(|domain| domain.domain.as_str())
+    }
+
+    /// Returns the timestamp indicating when the connection was created.
+    pub fn created_at(&self) -> &Timestamp {
+        &self.timestamps.created_at
+    }
+
+    /// Returns the timestamp indicating when the connection was last updated.
+    pub fn updated_at(&self) -> &Timestamp {
+        &self.timestamps.updated_at
+    }
+}
+
+/// The ID of a [`ConnectionDomain`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ConnectionDomainId(String);
+
+impl Display for ConnectionDomainId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for ConnectionDomainId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for ConnectionDomainId {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl FromStr for ConnectionDomainId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
+/// An email domain that is allowed to sign in using a [`Connection`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectionDomain {
+    /// The ID of the connection domain.
+    pub id: ConnectionDomainId,
+
+    /// The domain.
+    pub domain: String,
+}
+
 #[cfg(test)]
 mod test {
+    use std::collections::HashMap;
+
     use serde_json::json;
 
     use crate::organizations::OrganizationId;
     use crate::sso::ConnectionType;
     use crate::{KnownOrUnknown, Timestamp, Timestamps};
 
-    use super::{Connection, ConnectionId, ConnectionState};
+    use super::{Connection, ConnectionDomain, ConnectionDomainId, ConnectionId, ConnectionState};
+
+    #[test]
+    fn it_supports_keying_connections_by_id() {
+        let connection: Connection = serde_json::from_value(json!({
+          "object": "connection",
+          "id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+          "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+          "connection_type": "GoogleOAuth",
+          "name": "Foo Corp",
+          "state": "active",
+          "created_at": "2021-06-25T19:07:33.155Z",
+          "updated_at": "2021-06-25T19:07:33.155Z",
+        }))
+        .unwrap();
+
+        let updated_connection = Connection {
+            name: "Foo Corp (Renamed)".to_string(),
+            ..connection.clone()
+        };
+
+        let mut connections = HashMap::new();
+        connections.insert(connection.id.clone(), connection);
+        connections.insert(updated_connection.id.clone(), updated_connection);
+
+        assert_eq!(connections.len(), 1);
+        assert_eq!(
+            connections[&ConnectionId::from("conn_01E4ZCR3C56J083X43JQXF3JK5")].name,
+            "Foo Corp (Renamed)"
+        )
+    }
 
     #[test]
     fn it_deserializes_a_connection() {
@@ -94,10 +221,13 @@ mod test {
             connection,
             Connection {
                 id: ConnectionId::from("conn_01E4ZCR3C56J083X43JQXF3JK5"),
+                object: "connection".to_string(),
                 organization_id: Some(OrganizationId::from("org_01EHWNCE74X7JSDV0X3SZ3KJNY")),
                 r#type: KnownOrUnknown::Known(ConnectionType::GoogleOauth),
                 name: "Foo Corp".to_string(),
                 state: KnownOrUnknown::Known(ConnectionState::Active),
+                domains: Vec::new(),
+                attribute_mapping: None,
                 timestamps: Timestamps {
                     created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
                     updated_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
@@ -128,4 +258,175 @@ mod test {
             KnownOrUnknown::Unknown("UnknownType".to_string())
         )
     }
+
+    #[test]
+    fn it_round_trips_unknown_connection_types() {
+        let connection: Connection = serde_json::from_str(
+            &json!({
+              "object": "connection",
+              "id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+              "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+              "connection_type": "UnknownType",
+              "name": "Foo Corp",
+              "state": "unknown_state",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let serialized = serde_json::to_value(&connection).unwrap();
+
+        assert_eq!(serialized["connection_type"], json!("UnknownType"));
+        assert_eq!(serialized["state"], json!("unknown_state"));
+        assert_eq!(
+            serde_json::from_value::<Connection>(serialized).unwrap(),
+            connection
+        )
+    }
+
+    #[test]
+    fn it_deserializes_the_connection_domains() {
+        let connection: Connection = serde_json::from_str(
+            &json!({
+              "object": "connection",
+              "id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+              "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+              "connection_type": "OktaSAML",
+              "name": "Foo Corp",
+              "state": "active",
+              "domains": [
+                {
+                  "object": "connection_domain",
+                  "id": "conn_domain_01EHWNFTAFCF3CQAE5A9Q0P1YB",
+                  "domain": "foo-corp.com"
+                }
+              ],
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            connection.domains,
+            vec![ConnectionDomain {
+                id: ConnectionDomainId::from("conn_domain_01EHWNFTAFCF3CQAE5A9Q0P1YB"),
+                domain: "foo-corp.com".to_string(),
+            }]
+        )
+    }
+
+    #[test]
+    fn it_deserializes_the_attribute_mapping() {
+        let connection: Connection = serde_json::from_str(
+            &json!({
+              "object": "connection",
+              "id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+              "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+              "connection_type": "OktaSAML",
+              "name": "Foo Corp",
+              "state": "active",
+              "attribute_mapping": {
+                "idp_id": "user_id",
+                "email": "email_address",
+                "first_name": "given_name",
+                "last_name": "family_name"
+              },
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            connection.attribute_mapping,
+            Some(HashMap::from([
+                ("idp_id".to_string(), "user_id".to_string()),
+                ("email".to_string(), "email_address".to_string()),
+                ("first_name".to_string(), "given_name".to_string()),
+                ("last_name".to_string(), "family_name".to_string()),
+            ]))
+        )
+    }
+
+    #[test]
+    fn it_returns_the_first_domain_as_the_primary_domain() {
+        let connection: Connection = serde_json::from_value(json!({
+          "object": "connection",
+          "id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+          "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+          "connection_type": "OktaSAML",
+          "name": "Foo Corp",
+          "state": "active",
+          "domains": [
+            {
+              "object": "connection_domain",
+              "id": "conn_domain_01EHWNFTAFCF3CQAE5A9Q0P1YB",
+              "domain": "foo-corp.com"
+            },
+            {
+              "object": "connection_domain",
+              "id": "conn_domain_01EHWNFTAFCF3CQAE5A9Q0P1YC",
+              "domain": "foo-corp.co.uk"
+            }
+          ],
+          "created_at": "2021-06-25T19:07:33.155Z",
+          "updated_at": "2021-06-25T19:07:33.155Z",
+        }))
+        .unwrap();
+
+        assert_eq!(connection.primary_domain(), Some("foo-corp.com"))
+    }
+
+    #[test]
+    fn it_returns_no_primary_domain_when_there_are_no_domains() {
+        let connection: Connection = serde_json::from_value(json!({
+          "object": "connection",
+          "id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+          "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+          "connection_type": "GoogleOAuth",
+          "name": "Foo Corp",
+          "state": "active",
+          "created_at": "2021-06-25T19:07:33.155Z",
+          "updated_at": "2021-06-25T19:07:33.155Z",
+        }))
+        .unwrap();
+
+        assert_eq!(connection.primary_domain(), None)
+    }
+
+    #[test]
+    fn it_parses_a_connection_id() {
+        let id: ConnectionId = "conn_01E4ZCR3C56J083X43JQXF3JK5".parse().unwrap();
+
+        assert_eq!(id, ConnectionId::from("conn_01E4ZCR3C56J083X43JQXF3JK5"))
+    }
+
+    #[test]
+    fn it_returns_the_timestamps_of_the_connection() {
+        let connection: Connection = serde_json::from_value(json!({
+          "object": "connection",
+          "id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+          "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+          "connection_type": "GoogleOAuth",
+          "name": "Foo Corp",
+          "state": "active",
+          "created_at": "2021-06-25T19:07:33.155Z",
+          "updated_at": "2021-06-25T19:08:33.155Z",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            connection.created_at(),
+            &Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap()
+        );
+        assert_eq!(
+            connection.updated_at(),
+            &Timestamp::try_from("2021-06-25T19:08:33.155Z").unwrap()
+        )
+    }
 }
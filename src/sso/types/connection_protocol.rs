@@ -0,0 +1,12 @@
+/// The protocol family of a [`ConnectionType`](crate::sso::ConnectionType).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionProtocol {
+    /// OAuth.
+    Oauth,
+
+    /// OpenID Connect (OIDC).
+    Oidc,
+
+    /// SAML.
+    Saml,
+}
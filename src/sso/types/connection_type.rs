@@ -1,7 +1,12 @@
+use std::fmt::Display;
+
 use serde::{Deserialize, Serialize};
 
+use crate::sso::ConnectionProtocol;
+
 /// The type of a [`Connection`](crate::sso::Connection).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum ConnectionType {
     /// AD FS SAML.
     ///
@@ -166,10 +171,100 @@ pub enum ConnectionType {
     VmwareSaml,
 }
 
+impl ConnectionType {
+    /// Returns the string used to represent the connection type in the WorkOS API, such as
+    /// `"ADFSSAML"`.
+    pub fn as_api_str(&self) -> &'static str {
+        match self {
+            Self::AdFsSaml => "ADFSSAML",
+            Self::AdpOidc => "ADPOIDC",
+            Self::Auth0Saml => "Auth0SAML",
+            Self::AzureSaml => "AzureSAML",
+            Self::CasSaml => "CASSAML",
+            Self::ClassLinkSaml => "ClassLinkSAML",
+            Self::CloudflareSaml => "CloudflareSAML",
+            Self::CyberArkSaml => "CyberArkSAML",
+            Self::DuoSaml => "DuoSAML",
+            Self::GenericOidc => "GenericOIDC",
+            Self::GenericSaml => "GenericSAML",
+            Self::GoogleOauth => "GoogleOAuth",
+            Self::GoogleSaml => "GoogleSAML",
+            Self::JumpCloudSaml => "JumpCloudSAML",
+            Self::KeycloakSaml => "KeycloakSAML",
+            Self::MicrosoftOauth => "MicrosoftOAuth",
+            Self::MiniOrangeSaml => "MiniOrangeSAML",
+            Self::NetIqSaml => "NetIqSAML",
+            Self::OktaSaml => "OktaSAML",
+            Self::OneLoginSaml => "OneLoginSAML",
+            Self::OracleSaml => "OracleSAML",
+            Self::PingFederateSaml => "PingFederateSAML",
+            Self::PingOneSaml => "PingOneSAML",
+            Self::SalesforceSaml => "SalesforceSAML",
+            Self::ShibbolethSaml => "ShibbolethSAML",
+            Self::SimpleSamlPhpSaml => "SimpleSamlPhpSAML",
+            Self::VmwareSaml => "VMwareSAML",
+        }
+    }
+
+    /// Returns the protocol family used by the connection type.
+    pub fn protocol(&self) -> ConnectionProtocol {
+        match self {
+            Self::GoogleOauth | Self::MicrosoftOauth => ConnectionProtocol::Oauth,
+            Self::AdpOidc | Self::GenericOidc => ConnectionProtocol::Oidc,
+            Self::AdFsSaml
+            | Self::Auth0Saml
+            | Self::AzureSaml
+            | Self::CasSaml
+            | Self::ClassLinkSaml
+            | Self::CloudflareSaml
+            | Self::CyberArkSaml
+            | Self::DuoSaml
+            | Self::GenericSaml
+            | Self::GoogleSaml
+            | Self::JumpCloudSaml
+            | Self::KeycloakSaml
+            | Self::MiniOrangeSaml
+            | Self::NetIqSaml
+            | Self::OktaSaml
+            | Self::OneLoginSaml
+            | Self::OracleSaml
+            | Self::PingFederateSaml
+            | Self::PingOneSaml
+            | Self::SalesforceSaml
+            | Self::ShibbolethSaml
+            | Self::SimpleSamlPhpSaml
+            | Self::VmwareSaml => ConnectionProtocol::Saml,
+        }
+    }
+
+    /// Returns whether the connection type uses OAuth.
+    pub fn is_oauth(&self) -> bool {
+        self.protocol() == ConnectionProtocol::Oauth
+    }
+
+    /// Returns whether the connection type uses OpenID Connect (OIDC).
+    pub fn is_oidc(&self) -> bool {
+        self.protocol() == ConnectionProtocol::Oidc
+    }
+
+    /// Returns whether the connection type uses SAML.
+    pub fn is_saml(&self) -> bool {
+        self.protocol() == ConnectionProtocol::Saml
+    }
+}
+
+impl Display for ConnectionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_api_str())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use serde_json::json;
 
+    use crate::sso::ConnectionProtocol;
+
     use super::ConnectionType;
 
     #[test]
@@ -187,4 +282,51 @@ mod test {
             ConnectionType::AdpOidc
         )
     }
+
+    #[test]
+    fn it_returns_the_protocol_for_an_oauth_connection_type() {
+        assert_eq!(
+            ConnectionType::GoogleOauth.protocol(),
+            ConnectionProtocol::Oauth
+        );
+        assert!(ConnectionType::GoogleOauth.is_oauth());
+        assert!(!ConnectionType::GoogleOauth.is_saml());
+    }
+
+    #[test]
+    fn it_returns_the_protocol_for_an_oidc_connection_type() {
+        assert_eq!(ConnectionType::AdpOidc.protocol(), ConnectionProtocol::Oidc);
+        assert!(ConnectionType::AdpOidc.is_oidc());
+        assert!(!ConnectionType::AdpOidc.is_oauth());
+    }
+
+    #[test]
+    fn it_returns_the_protocol_for_a_saml_connection_type() {
+        assert_eq!(
+            ConnectionType::OktaSaml.protocol(),
+            ConnectionProtocol::Saml
+        );
+        assert!(ConnectionType::OktaSaml.is_saml());
+        assert!(!ConnectionType::OktaSaml.is_oidc());
+    }
+
+    #[test]
+    fn it_returns_the_api_string_matching_the_serialized_value() {
+        for connection_type in [
+            ConnectionType::OktaSaml,
+            ConnectionType::GoogleOauth,
+            ConnectionType::AdpOidc,
+            ConnectionType::SimpleSamlPhpSaml,
+        ] {
+            assert_eq!(
+                json!(connection_type.as_api_str()),
+                serde_json::to_value(&connection_type).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn it_displays_the_api_string() {
+        assert_eq!(ConnectionType::OktaSaml.to_string(), "OktaSAML")
+    }
 }
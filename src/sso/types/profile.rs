@@ -1,4 +1,6 @@
+use std::convert::Infallible;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
@@ -8,7 +10,7 @@ use crate::{KnownOrUnknown, RawAttributes};
 use super::{ConnectionId, ConnectionType};
 
 /// The ID of a [`Profile`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct ProfileId(String);
 
 impl Display for ProfileId {
@@ -29,6 +31,14 @@ impl From<&str> for ProfileId {
     }
 }
 
+impl FromStr for ProfileId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
 /// [WorkOS Docs: Profile](https://workos.com/docs/reference/sso/profile)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Profile {
@@ -59,3 +69,73 @@ pub struct Profile {
     /// The raw attributes received from the Identity Provider.
     pub raw_attributes: RawAttributes,
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::organizations::OrganizationId;
+    use crate::sso::ConnectionId;
+
+    use super::Profile;
+
+    #[test]
+    fn it_reads_a_saml_attribute_from_the_raw_attributes() {
+        let profile: Profile = serde_json::from_str(
+            &json!({
+              "object": "profile",
+              "id": "prof_01DMC79VCBZ0NY2099737PSVF1",
+              "connection_id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+              "connection_type": "OktaSAML",
+              "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+              "email": "todd@foo-corp.com",
+              "first_name": "Todd",
+              "last_name": "Rundgren",
+              "idp_id": "00u1a0ufowBJlzPlk357",
+              "raw_attributes": {
+                "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/department": "Engineering",
+                "groups": ["Admins", "Developers"]
+              }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            profile
+                .raw_attributes
+                .get_str("http://schemas.xmlsoap.org/ws/2005/05/identity/claims/department"),
+            Some("Engineering")
+        );
+        assert_eq!(
+            profile.raw_attributes.get("groups"),
+            Some(&json!(["Admins", "Developers"]))
+        )
+    }
+
+    #[test]
+    fn it_deserializes_the_typed_connection_and_organization_ids() {
+        let profile: Profile = serde_json::from_value(json!({
+          "object": "profile",
+          "id": "prof_01DMC79VCBZ0NY2099737PSVF1",
+          "connection_id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+          "connection_type": "OktaSAML",
+          "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+          "email": "todd@foo-corp.com",
+          "first_name": "Todd",
+          "last_name": "Rundgren",
+          "idp_id": "00u1a0ufowBJlzPlk357",
+          "raw_attributes": {}
+        }))
+        .unwrap();
+
+        assert_eq!(
+            profile.connection_id,
+            ConnectionId::from("conn_01E4ZCR3C56J083X43JQXF3JK5")
+        );
+        assert_eq!(
+            profile.organization_id,
+            Some(OrganizationId::from("org_01EHWNCE74X7JSDV0X3SZ3KJNY"))
+        )
+    }
+}
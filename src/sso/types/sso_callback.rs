@@ -0,0 +1,165 @@
+use thiserror::Error;
+use url::Url;
+
+use crate::sso::GetProfileAndTokenParams;
+use crate::{AuthorizationCode, ClientId};
+
+/// The query parameters passed to the redirect URI when SSO completes.
+///
+/// # Examples
+///
+/// ```
+/// # use workos::sso::*;
+/// use url::Url;
+/// use workos::ClientId;
+///
+/// # fn run() -> Result<(), SsoCallbackError> {
+/// let callback_url =
+///     Url::parse("https://your-app.com/callback?code=01E2RJ4C05B52KKZ8FSRDAP23J&state=abc123")
+///         .unwrap();
+///
+/// let callback = SsoCallback::try_from(&callback_url)?;
+/// let client_id = ClientId::from("client_123456789");
+/// let params = callback.params(&client_id);
+///
+/// assert_eq!(callback.state.as_deref(), Some("abc123"));
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SsoCallback {
+    /// The authorization code to exchange for the profile and token.
+    pub code: AuthorizationCode,
+
+    /// The state that was passed when the authorization URL was created.
+    ///
+    /// State created with [`EncodedState`](crate::EncodedState) can be decoded using
+    /// `EncodedState::from(state).decode()`.
+    pub state: Option<String>,
+}
+
+/// An error returned when parsing an [`SsoCallback`].
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SsoCallbackError {
+    /// The redirect did not include an authorization code.
+    #[error("callback did not include an authorization code")]
+    MissingCode,
+
+    /// SSO failed and WorkOS redirected with an error.
+    #[error("SSO failed: {error}")]
+    Error {
+        /// The error code.
+        error: String,
+
+        /// The description of the error.
+        error_description: Option<String>,
+
+        /// The state that was passed when the authorization URL was created.
+        state: Option<String>,
+    },
+}
+
+impl SsoCallback {
+    /// Returns the parameters for exchanging the authorization code for a profile
+    /// and token with [`GetProfileAndToken`](crate::sso::GetProfileAndToken).
+    pub fn params<'a>(&'a self, client_id: &'a ClientId) -> GetProfileAndTokenParams<'a> {
+        GetProfileAndTokenParams {
+            client_id,
+            code: &self.code,
+        }
+    }
+}
+
+impl TryFrom<&Url> for SsoCallback {
+    type Error = SsoCallbackError;
+
+    fn try_from(url: &Url) -> Result<Self, Self::Error> {
+        let mut code = None;
+        let mut state = None;
+        let mut error = None;
+        let mut error_description = None;
+
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                "code" => code = Some(value.into_owned()),
+                "state" => state = Some(value.into_owned()),
+                "error" => error = Some(value.into_owned()),
+                "error_description" => error_description = Some(value.into_owned()),
+                _ => {}
+            }
+        }
+
+        if let Some(error) = error {
+            return Err(SsoCallbackError::Error {
+                error,
+                error_description,
+                state,
+            });
+        }
+
+        Ok(Self {
+            code: AuthorizationCode::from(code.ok_or(SsoCallbackError::MissingCode)?),
+            state,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_parses_a_callback_url() {
+        let url = Url::parse(
+            "https://your-app.com/sso/callback?code=01E2RJ4C05B52KKZ8FSRDAP23J&state=eyJyZXR1cm5fdG8iOiIvZGFzaGJvYXJkIn0",
+        )
+        .unwrap();
+
+        let callback = SsoCallback::try_from(&url).unwrap();
+
+        assert_eq!(
+            callback,
+            SsoCallback {
+                code: AuthorizationCode::from("01E2RJ4C05B52KKZ8FSRDAP23J"),
+                state: Some("eyJyZXR1cm5fdG8iOiIvZGFzaGJvYXJkIn0".to_string()),
+            }
+        );
+
+        let client_id = ClientId::from("client_123456789");
+        let params = callback.params(&client_id);
+
+        assert_eq!(params.client_id, &client_id);
+        assert_eq!(
+            params.code,
+            &AuthorizationCode::from("01E2RJ4C05B52KKZ8FSRDAP23J")
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_when_the_code_is_missing() {
+        let url = Url::parse("https://your-app.com/sso/callback?state=abc123").unwrap();
+
+        assert_eq!(
+            SsoCallback::try_from(&url),
+            Err(SsoCallbackError::MissingCode)
+        )
+    }
+
+    #[test]
+    fn it_returns_the_error_from_a_failed_callback() {
+        let url = Url::parse(
+            "https://your-app.com/sso/callback?error=access_denied&error_description=The+user+denied+access&state=abc123",
+        )
+        .unwrap();
+
+        assert_eq!(
+            SsoCallback::try_from(&url),
+            Err(SsoCallbackError::Error {
+                error: "access_denied".to_string(),
+                error_description: Some("The user denied access".to_string()),
+                state: Some("abc123".to_string()),
+            })
+        )
+    }
+}
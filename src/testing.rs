@@ -0,0 +1,154 @@
+//! Fixtures for building WorkOS resources in tests.
+//!
+//! Each function returns a resource populated with placeholder values, which can be
+//! customized using struct update syntax.
+//!
+//! This module is only available when the `testing` feature is enabled.
+//!
+//! # Examples
+//!
+//! ```
+//! use workos::directory_sync::DirectoryUser;
+//! use workos::testing;
+//!
+//! let directory_user = DirectoryUser {
+//!     first_name: Some("Marcelina".to_string()),
+//!     ..testing::directory_user("directory_user_01E1JG7J09H96KYP8HM9B0G5SJ")
+//! };
+//!
+//! let page = testing::paginated_list(vec![directory_user]);
+//! assert_eq!(page.data[0].first_name, Some("Marcelina".to_string()));
+//! ```
+
+#[cfg(feature = "crypto")]
+mod totp;
+
+use std::collections::HashMap;
+
+use chrono::DateTime;
+
+use crate::directory_sync::{
+    Directory, DirectoryGroup, DirectoryGroupId, DirectoryId, DirectoryState, DirectoryType,
+    DirectoryUser, DirectoryUserEmail, DirectoryUserId, DirectoryUserState,
+};
+use crate::organizations::{Organization, OrganizationId};
+use crate::sso::{Connection, ConnectionId, ConnectionState, ConnectionType, Profile, ProfileId};
+use crate::{KnownOrUnknown, ListMetadata, PaginatedList, RawAttributes, Timestamp, Timestamps};
+
+#[cfg(feature = "crypto")]
+pub use totp::*;
+
+/// Returns a [`PaginatedList`] containing the given records and no further pages.
+pub fn paginated_list<T>(data: Vec<T>) -> PaginatedList<T> {
+    PaginatedList {
+        data,
+        metadata: ListMetadata {
+            before: None,
+            after: None,
+        },
+    }
+}
+
+/// Returns [`Timestamps`] set to the Unix epoch.
+pub fn timestamps() -> Timestamps {
+    let epoch = Timestamp(DateTime::UNIX_EPOCH.fixed_offset());
+
+    Timestamps {
+        created_at: epoch.clone(),
+        updated_at: epoch,
+    }
+}
+
+/// Returns an active [`Connection`] with the given ID.
+pub fn connection(id: &str) -> Connection {
+    Connection {
+        id: ConnectionId::from(id),
+        object: "connection".to_string(),
+        organization_id: None,
+        r#type: KnownOrUnknown::Known(ConnectionType::GenericSaml),
+        name: "Test Connection".to_string(),
+        state: KnownOrUnknown::Known(ConnectionState::Active),
+        domains: Vec::new(),
+        attribute_mapping: None,
+        timestamps: timestamps(),
+    }
+}
+
+/// Returns an active [`Directory`] with the given ID.
+pub fn directory(id: &str) -> Directory {
+    Directory {
+        id: DirectoryId::from(id),
+        object: "directory".to_string(),
+        organization_id: None,
+        r#type: KnownOrUnknown::Known(DirectoryType::GenericScimV2_0),
+        state: KnownOrUnknown::Known(DirectoryState::Active),
+        name: "Test Directory".to_string(),
+        domain: None,
+        external_key: None,
+        timestamps: timestamps(),
+    }
+}
+
+/// Returns a [`DirectoryGroup`] with the given ID.
+pub fn directory_group(id: &str) -> DirectoryGroup {
+    DirectoryGroup {
+        id: DirectoryGroupId::from(id),
+        idp_id: id.to_string(),
+        directory_id: DirectoryId::from("directory_test"),
+        organization_id: None,
+        name: "Test Group".to_string(),
+        timestamps: timestamps(),
+        raw_attributes: RawAttributes(HashMap::new()),
+    }
+}
+
+/// Returns an active [`DirectoryUser`] with the given ID.
+pub fn directory_user(id: &str) -> DirectoryUser {
+    DirectoryUser {
+        id: DirectoryUserId::from(id),
+        idp_id: id.to_string(),
+        directory_id: DirectoryId::from("directory_test"),
+        organization_id: None,
+        username: Some("test@example.com".to_string()),
+        emails: vec![DirectoryUserEmail {
+            primary: Some(true),
+            r#type: Some("work".to_string()),
+            value: Some("test@example.com".to_string()),
+        }],
+        first_name: None,
+        last_name: None,
+        state: KnownOrUnknown::Known(DirectoryUserState::Active),
+        custom_attributes: HashMap::new(),
+        raw_attributes: RawAttributes(HashMap::new()),
+        timestamps: timestamps(),
+    }
+}
+
+/// Returns an [`Organization`] with the given ID.
+#[allow(deprecated)]
+pub fn organization(id: &str) -> Organization {
+    Organization {
+        id: OrganizationId::from(id),
+        object: "organization".to_string(),
+        name: "Test Organization".to_string(),
+        allow_profiles_outside_organization: false,
+        domains: Vec::new(),
+        metadata: HashMap::new(),
+        timestamps: timestamps(),
+    }
+}
+
+/// Returns a [`Profile`] with the given ID.
+pub fn profile(id: &str) -> Profile {
+    Profile {
+        id: ProfileId::from(id),
+        connection_id: ConnectionId::from("conn_test"),
+        organization_id: None,
+        connection_type: KnownOrUnknown::Known(ConnectionType::GenericSaml),
+        idp_id: id.to_string(),
+        email: "test@example.com".to_string(),
+        first_name: None,
+        last_name: None,
+        raw_attributes: RawAttributes(HashMap::new()),
+    }
+}
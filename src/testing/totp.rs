@@ -0,0 +1,165 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::core::crypto::{Hmac, HmacAlgorithm};
+use crate::mfa::TotpUri;
+
+/// Returns the current time-based one-time password (TOTP) code for the given
+/// `otpauth://` URI.
+///
+/// The URI is the `uri` returned when enrolling a TOTP factor, which can be parsed
+/// using [`AuthenticationFactorType::parse_uri`](crate::mfa::AuthenticationFactorType::parse_uri).
+/// Codes are generated using the `algorithm`, `digits`, and `period` of the URI, which
+/// default to HMAC-SHA1, six digits, and a 30 second period.
+///
+/// This is only available when the `testing` and `crypto` features are enabled.
+///
+/// # Panics
+///
+/// Panics if the secret is not valid base32, or the algorithm is not one of `SHA1`,
+/// `SHA256`, or `SHA512`.
+///
+/// # Examples
+///
+/// ```
+/// use workos::mfa::TotpUri;
+/// use workos::testing;
+///
+/// let uri = TotpUri::parse(
+///     "otpauth://totp/FooCorp:alan.turing@foo-corp.com?secret=NAGCCFS3EYRB422HNAKAKY3XDUORMSRF",
+/// )
+/// .unwrap();
+///
+/// let code = testing::totp_now(&uri);
+///
+/// assert_eq!(code.len(), 6);
+/// ```
+pub fn totp_now(uri: &TotpUri) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time is after the Unix epoch");
+
+    totp_at(uri, now.as_secs())
+}
+
+/// Returns the time-based one-time password (TOTP) code for the given `otpauth://`
+/// URI at the given Unix time, in seconds.
+///
+/// See [`totp_now`] for details.
+///
+/// # Panics
+///
+/// Panics if the secret is not valid base32, or the algorithm is not one of `SHA1`,
+/// `SHA256`, or `SHA512`.
+pub fn totp_at(uri: &TotpUri, unix_time: u64) -> String {
+    let key = decode_base32(&uri.secret).expect("TOTP secret is not valid base32");
+    let algorithm = match uri.algorithm.to_ascii_uppercase().as_str() {
+        "SHA1" => HmacAlgorithm::Sha1,
+        "SHA256" => HmacAlgorithm::Sha256,
+        "SHA512" => HmacAlgorithm::Sha512,
+        algorithm => panic!("unsupported TOTP algorithm {algorithm:?}"),
+    };
+
+    let mut hmac = Hmac::new(algorithm, &key);
+    hmac.update(&(unix_time / uri.period).to_be_bytes());
+    let digest = hmac.finish();
+
+    // RFC 4226, section 5.3: dynamic truncation.
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+
+    format!(
+        "{:0width$}",
+        u64::from(binary) % 10u64.pow(uri.digits),
+        width = uri.digits as usize
+    )
+}
+
+/// Decodes an RFC 4648 base32 string, ignoring case, whitespace, and padding.
+fn decode_base32(input: &str) -> Option<Vec<u8>> {
+    let mut output = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for c in input.chars() {
+        let value = match c.to_ascii_uppercase() {
+            c @ 'A'..='Z' => c as u32 - 'A' as u32,
+            c @ '2'..='7' => c as u32 - '2' as u32 + 26,
+            '=' | ' ' => continue,
+            _ => return None,
+        };
+
+        buffer = (buffer << 5) | value;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// The RFC 6238 SHA-1 test secret, `12345678901234567890`, encoded as base32.
+    const RFC_6238_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+    fn uri(query: &str) -> TotpUri {
+        TotpUri::parse(&format!("otpauth://totp/FooCorp:alan.turing?{query}")).unwrap()
+    }
+
+    #[test]
+    fn it_generates_the_rfc_6238_test_vectors() {
+        // RFC 6238, appendix B, truncated to six digits.
+        let uri = uri(&format!("secret={RFC_6238_SECRET}"));
+
+        assert_eq!(totp_at(&uri, 59), "287082");
+        assert_eq!(totp_at(&uri, 1111111109), "081804");
+        assert_eq!(totp_at(&uri, 1111111111), "050471");
+        assert_eq!(totp_at(&uri, 1234567890), "005924");
+        assert_eq!(totp_at(&uri, 2000000000), "279037");
+    }
+
+    #[test]
+    fn it_uses_the_algorithm_and_digits_of_the_uri() {
+        // RFC 6238, appendix B.
+        let sha1 = uri(&format!("secret={RFC_6238_SECRET}&digits=8"));
+        let sha256 = uri(
+            "secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQGEZA&algorithm=SHA256&digits=8",
+        );
+        let sha512 = uri(
+            "secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQGEZDGNA&algorithm=SHA512&digits=8",
+        );
+
+        assert_eq!(totp_at(&sha1, 59), "94287082");
+        assert_eq!(totp_at(&sha256, 59), "46119246");
+        assert_eq!(totp_at(&sha512, 59), "90693936");
+    }
+
+    #[test]
+    fn it_uses_the_period_of_the_uri() {
+        let uri = uri(&format!("secret={RFC_6238_SECRET}&period=60"));
+
+        // With a 60 second period, time 118 uses the same counter as time 59 does with
+        // the default 30 second period.
+        assert_eq!(totp_at(&uri, 118), "287082");
+    }
+
+    #[test]
+    fn it_decodes_base32_secrets() {
+        assert_eq!(
+            decode_base32("gezdgnbvgy3tqojq====").as_deref(),
+            Some(&b"1234567890"[..])
+        );
+        assert_eq!(decode_base32("not base32!"), None);
+    }
+}
@@ -1,5 +1,17 @@
 mod authenticate_with_code;
+mod create_organization_membership;
+mod deactivate_organization_membership;
 mod get_user;
+mod list_auth_factors;
+mod list_organization_memberships;
+mod reactivate_organization_membership;
+mod update_organization_membership;
 
 pub use authenticate_with_code::*;
+pub use create_organization_membership::*;
+pub use deactivate_organization_membership::*;
 pub use get_user::*;
+pub use list_auth_factors::*;
+pub use list_organization_memberships::*;
+pub use reactivate_organization_membership::*;
+pub use update_organization_membership::*;
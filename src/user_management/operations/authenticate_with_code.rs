@@ -3,18 +3,29 @@ use reqwest::{Response, StatusCode};
 use serde::Deserialize;
 use thiserror::Error;
 
-use crate::user_management::{User, UserManagement};
-use crate::{AuthorizationCode, ClientId, WorkOsError, WorkOsResult};
+use crate::user_management::{
+    AuthenticationMethod, Impersonator, RefreshToken, User, UserManagement,
+};
+use crate::{
+    AuthorizationCode, ClientId, ClientSecret, KnownOrUnknown, RequestExt, ResponseExt,
+    WorkOsError, WorkOsResult,
+};
 
 /// The parameters for [`AuthenticateWithCode`].
 #[derive(Debug)]
 pub struct AuthenticateWithCodeParams<'a> {
     /// The client ID corresponding to the environment that SSO was initiated
     /// from.
-    pub client_id: &'a ClientId,
+    ///
+    /// When [`None`], the default client ID of the client is used, which is set using
+    /// [`WorkOsBuilder::client_id`](crate::WorkOsBuilder::client_id).
+    pub client_id: Option<&'a ClientId>,
 
     /// The client secret corresponding to the environment that SSO was initiated.
-    pub client_secret: String,
+    ///
+    /// When [`None`], the API key of the client is used as the client secret, in the
+    /// same way as [`GetProfileAndToken`](crate::sso::GetProfileAndToken).
+    pub client_secret: Option<&'a ClientSecret>,
 
     /// The grant type of the request.
     /// This should always be "authorization_code".
@@ -38,17 +49,46 @@ pub struct AuthenticateWithCodeResponse {
 
     /// The ID of the organization that the user is a member of.
     pub organization_id: Option<String>,
+
+    /// The admin who is impersonating the user, if the session was started through
+    /// impersonation.
+    ///
+    /// Applications should record this in their audit logs.
+    pub impersonator: Option<Impersonator>,
+
+    /// The method the user used to authenticate.
+    ///
+    /// This can be used to require a stronger method before allowing sensitive
+    /// actions.
+    pub authentication_method: Option<KnownOrUnknown<AuthenticationMethod, String>>,
+
+    /// The refresh token that can be used to obtain a new session for the user.
+    pub refresh_token: Option<RefreshToken>,
 }
 
-/// An error returned from [`GetProfileAndToken`].
-#[derive(Debug, Error, Deserialize)]
-#[error("{error}: {error_description}")]
-pub struct AuthenticateWithCodeError {
-    /// The error code of the error that occurred.
-    pub error: String,
+/// An error returned from [`AuthenticateWithCode`].
+#[derive(Debug, Error)]
+pub enum AuthenticateWithCodeError {
+    /// No client ID was provided and the client does not have a default client ID.
+    #[error("no client ID was provided")]
+    MissingClientId,
+
+    /// The WorkOS API rejected the authorization code.
+    #[error("{error}: {error_description}")]
+    Rejected {
+        /// The error code of the error that occurred.
+        error: String,
+
+        /// The description of the error.
+        error_description: String,
+    },
+}
 
-    /// The description of the error.
-    pub error_description: String,
+/// The body of an error response from the authenticate endpoint.
+#[derive(Deserialize)]
+struct AuthenticateWithCodeErrorBody {
+    error: String,
+    error_description: String,
 }
 
 #[async_trait]
@@ -70,11 +110,17 @@ impl HandleAuthenticateWithCodeError for Response {
             Ok(_) => Ok(self),
             Err(err) => match err.status() {
                 Some(StatusCode::BAD_REQUEST) => {
-                    let error = self.json::<AuthenticateWithCodeError>().await?;
+                    let AuthenticateWithCodeErrorBody {
+                        error,
+                        error_description,
+                    } = self.parse_json().await?;
 
-                    Err(match error.error.as_str() {
+                    Err(match error.as_str() {
                         "invalid_client" | "unauthorized_client" => WorkOsError::Unauthorized,
-                        _ => WorkOsError::Operation(error),
+                        _ => WorkOsError::Operation(AuthenticateWithCodeError::Rejected {
+                            error,
+                            error_description,
+                        }),
                     })
                 }
                 _ => Err(WorkOsError::RequestError(err)),
@@ -93,7 +139,7 @@ pub trait AuthenticateWithCode {
     /// ```
     /// # use workos::WorkOsResult;
     /// # use workos::user_management::*;
-    /// use workos::{AuthorizationCode, ApiKey, ClientId, WorkOs};
+    /// use workos::{AuthorizationCode, ApiKey, ClientId, ClientSecret, WorkOs};
     ///
     /// # async fn run() -> WorkOsResult<(), AuthenticateWithCodeError> {
     /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
@@ -101,8 +147,8 @@ pub trait AuthenticateWithCode {
     /// let AuthenticateWithCodeResponse { user, .. } = workos
     ///     .user_management()
     ///     .authenticate_with_code(&AuthenticateWithCodeParams {
-    ///         client_id: &ClientId::from("client_1234"),
-    ///         client_secret: "client secret".to_string(),
+    ///         client_id: Some(&ClientId::from("client_1234")),
+    ///         client_secret: Some(&ClientSecret::from("client secret")),
     ///         grant_type: "authorization_code".to_string(),
     ///         code: &AuthorizationCode::from("code_1234"),
     ///         ip_address: "1.2.3.4".to_string(),
@@ -133,17 +179,27 @@ impl<'a> AuthenticateWithCode for UserManagement<'a> {
             user_agent,
         } = params;
 
+        let client_id = client_id
+            .or(self.workos.client_id())
+            .ok_or(WorkOsError::Operation(
+                AuthenticateWithCodeError::MissingClientId,
+            ))?;
+
         let url = self
             .workos
             .base_url()
             .join("/user_management/authenticate")?;
+        let client_secret = match client_secret {
+            Some(client_secret) => client_secret.expose_secret().to_string(),
+            None => self.workos.key().to_string(),
+        };
         let params = [
             ("client_id", &client_id.to_string()),
             ("client_secret", &client_secret),
-            ("grant_type", &grant_type),
+            ("grant_type", grant_type),
             ("code", &code.to_string()),
-            ("ip_address", &ip_address),
-            ("user_agent", &user_agent),
+            ("ip_address", ip_address),
+            ("user_agent", user_agent),
         ];
 
         let authenticate_with_code_response = self
@@ -151,11 +207,11 @@ impl<'a> AuthenticateWithCode for UserManagement<'a> {
             .client()
             .post(url)
             .form(&params)
-            .send()
+            .send_instrumented(self.workos)
             .await?
             .handle_authenticate_with_code_error()
             .await?
-            .json::<AuthenticateWithCodeResponse>()
+            .parse_json::<AuthenticateWithCodeResponse, _>()
             .await?;
 
         Ok(authenticate_with_code_response)
@@ -199,7 +255,8 @@ mod test {
                     "created_at": "2021-06-25T19:07:33.155Z",
                     "updated_at": "2021-06-25T19:07:33.155Z"
                   },
-                  "organization_id": "org_01H945H0YD4F97JN9MATX7BYAG"
+                  "organization_id": "org_01H945H0YD4F97JN9MATX7BYAG",
+                  "refresh_token": "yAjhKk123NLIjdrBdGZPf8pLIDvK"
                 })
                 .to_string(),
             )
@@ -213,8 +270,8 @@ mod test {
         let response = workos
             .user_management()
             .authenticate_with_code(&AuthenticateWithCodeParams {
-                client_id: &ClientId::from("client_1234"),
-                client_secret: "client".into(),
+                client_id: Some(&ClientId::from("client_1234")),
+                client_secret: Some(&ClientSecret::from("client")),
                 grant_type: "authorization_code".into(),
                 code: &AuthorizationCode::from("abc123"),
                 ip_address: "1.2.3.4".into(),
@@ -230,13 +287,65 @@ mod test {
         assert_eq!(response.user.email, "marcelina.davis@example.com");
         assert_eq!(response.user.first_name, "Marcelina");
         assert_eq!(response.user.last_name, "Davis");
-        assert_eq!(response.user.email_verified, true);
+        assert!(response.user.email_verified);
         assert_eq!(response.user.created_at, "2021-06-25T19:07:33.155Z");
         assert_eq!(response.user.updated_at, "2021-06-25T19:07:33.155Z");
         assert_eq!(
             response.organization_id,
             Some("org_01H945H0YD4F97JN9MATX7BYAG".to_string())
         );
+        assert_eq!(
+            response.refresh_token,
+            Some(RefreshToken::from("yAjhKk123NLIjdrBdGZPf8pLIDvK"))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_uses_the_api_key_when_the_client_secret_is_not_provided() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/user_management/authenticate")
+            .match_body(Matcher::UrlEncoded(
+                "client_secret".into(),
+                "sk_example_123456789".into(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "user": {
+                    "object": "user",
+                    "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                    "email": "marcelina.davis@example.com",
+                    "first_name": "Marcelina",
+                    "last_name": "Davis",
+                    "email_verified": true,
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z"
+                  }
+                })
+                .to_string(),
+            )
+            .create();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        workos
+            .user_management()
+            .authenticate_with_code(&AuthenticateWithCodeParams {
+                client_id: Some(&ClientId::from("client_1234")),
+                client_secret: None,
+                grant_type: "authorization_code".into(),
+                code: &AuthorizationCode::from("abc123"),
+                ip_address: "1.2.3.4".into(),
+                user_agent: "Mozilla/5.0".into(),
+            })
+            .await
+            .unwrap();
+
+        mock.assert();
     }
 
     #[tokio::test]
@@ -262,8 +371,8 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_code(&AuthenticateWithCodeParams {
-                client_id: &ClientId::from("client_1234"),
-                client_secret: "client".into(),
+                client_id: Some(&ClientId::from("client_1234")),
+                client_secret: Some(&ClientSecret::from("client")),
                 grant_type: "authorization_code".into(),
                 code: &AuthorizationCode::from("abc123"),
                 ip_address: "1.2.3.4".into(),
@@ -297,8 +406,8 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_code(&AuthenticateWithCodeParams {
-                client_id: &ClientId::from("client_1234"),
-                client_secret: "client".into(),
+                client_id: Some(&ClientId::from("client_1234")),
+                client_secret: Some(&ClientSecret::from("client")),
                 grant_type: "authorization_code".into(),
                 code: &AuthorizationCode::from("abc123"),
                 ip_address: "1.2.3.4".into(),
@@ -332,8 +441,75 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_code(&AuthenticateWithCodeParams {
-                client_id: &ClientId::from("client_1234"),
-                client_secret: "client".into(),
+                client_id: Some(&ClientId::from("client_1234")),
+                client_secret: Some(&ClientSecret::from("client")),
+                grant_type: "authorization_code".into(),
+                code: &AuthorizationCode::from("abc123"),
+                ip_address: "1.2.3.4".into(),
+                user_agent: "Mozilla/5.0".into(),
+            })
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(AuthenticateWithCodeError::Rejected {
+                error,
+                error_description,
+            })) if error == "invalid_grant"
+                && error_description == "The code 'abc123' has expired or is invalid."
+        )
+    }
+
+    #[tokio::test]
+    async fn it_uses_the_default_client_id_when_the_client_id_is_not_provided() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/user_management/authenticate")
+            .match_body(Matcher::UrlEncoded(
+                "client_id".into(),
+                "client_default".into(),
+            ))
+            .with_status(400)
+            .with_body(
+                json!({
+                    "error": "invalid_grant",
+                    "error_description": "The code 'abc123' has expired or is invalid."
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client_id = ClientId::from("client_default");
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .client_id(&client_id)
+            .build();
+
+        let _ = workos
+            .user_management()
+            .authenticate_with_code(&AuthenticateWithCodeParams {
+                client_id: None,
+                client_secret: None,
+                grant_type: "authorization_code".into(),
+                code: &AuthorizationCode::from("abc123"),
+                ip_address: "1.2.3.4".into(),
+                user_agent: "Mozilla/5.0".into(),
+            })
+            .await;
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_no_client_id_is_available() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let result = workos
+            .user_management()
+            .authenticate_with_code(&AuthenticateWithCodeParams {
+                client_id: None,
+                client_secret: None,
                 grant_type: "authorization_code".into(),
                 code: &AuthorizationCode::from("abc123"),
                 ip_address: "1.2.3.4".into(),
@@ -341,14 +517,98 @@ mod test {
             })
             .await;
 
-        if let Err(WorkOsError::Operation(error)) = result {
-            assert_eq!(error.error, "invalid_grant");
-            assert_eq!(
-                error.error_description,
-                "The code 'abc123' has expired or is invalid."
-            );
-        } else {
-            panic!("expected get_profile_and_token to return an error")
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(
+                AuthenticateWithCodeError::MissingClientId
+            ))
+        )
+    }
+
+    #[test]
+    fn it_deserializes_the_impersonator() {
+        let response: AuthenticateWithCodeResponse = serde_json::from_value(json!({
+          "user": {
+            "object": "user",
+            "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+            "email": "marcelina.davis@example.com",
+            "first_name": "Marcelina",
+            "last_name": "Davis",
+            "email_verified": true,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+          },
+          "organization_id": "org_01H945H0YD4F97JN9MATX7BYAG",
+          "impersonator": {
+            "email": "admin@foocorp.com",
+            "reason": "Investigating an issue with the customer's account."
+          }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response.impersonator,
+            Some(Impersonator {
+                email: "admin@foocorp.com".to_string(),
+                reason: Some("Investigating an issue with the customer's account.".to_string()),
+            })
+        )
+    }
+
+    #[test]
+    fn it_deserializes_a_response_without_an_impersonator() {
+        let response: AuthenticateWithCodeResponse = serde_json::from_value(json!({
+          "user": {
+            "object": "user",
+            "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+            "email": "marcelina.davis@example.com",
+            "first_name": "Marcelina",
+            "last_name": "Davis",
+            "email_verified": true,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+          },
+          "organization_id": "org_01H945H0YD4F97JN9MATX7BYAG"
+        }))
+        .unwrap();
+
+        assert_eq!(response.impersonator, None)
+    }
+
+    #[test]
+    fn it_deserializes_the_authentication_method() {
+        for (authentication_method, expected) in [
+            ("SSO", KnownOrUnknown::Known(AuthenticationMethod::Sso)),
+            (
+                "MagicAuth",
+                KnownOrUnknown::Known(AuthenticationMethod::MagicAuth),
+            ),
+            (
+                "GoogleOAuth",
+                KnownOrUnknown::Known(AuthenticationMethod::GoogleOauth),
+            ),
+            (
+                "CarrierPigeon",
+                KnownOrUnknown::Unknown("CarrierPigeon".to_string()),
+            ),
+        ] {
+            let response: AuthenticateWithCodeResponse = serde_json::from_value(json!({
+              "user": {
+                "object": "user",
+                "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                "email": "marcelina.davis@example.com",
+                "first_name": "Marcelina",
+                "last_name": "Davis",
+                "email_verified": true,
+                "created_at": "2021-06-25T19:07:33.155Z",
+                "updated_at": "2021-06-25T19:07:33.155Z"
+              },
+              "organization_id": "org_01H945H0YD4F97JN9MATX7BYAG",
+              "authentication_method": authentication_method
+            }))
+            .unwrap();
+
+            assert_eq!(response.authentication_method, Some(expected));
         }
     }
 }
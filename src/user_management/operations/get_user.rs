@@ -4,7 +4,7 @@ use serde::Deserialize;
 use thiserror::Error;
 
 use crate::user_management::{User, UserManagement};
-use crate::{WorkOsError, WorkOsResult};
+use crate::{RequestExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`GetUser`].
 #[derive(Debug)]
@@ -106,11 +106,11 @@ impl<'a> GetUser for UserManagement<'a> {
 
         let request = self.workos.client().get(url).bearer_auth(self.workos.key());
         let get_user_response = request
-            .send()
+            .send_instrumented(self.workos)
             .await?
             .handle_get_user_error()
             .await?
-            .json::<GetUserResponse>()
+            .parse_json::<GetUserResponse, _>()
             .await?;
 
         Ok(get_user_response)
@@ -172,7 +172,7 @@ mod test {
         assert_eq!(response.user.email, "marcelina.davis@example.com");
         assert_eq!(response.user.first_name, "Marcelina");
         assert_eq!(response.user.last_name, "Davis");
-        assert_eq!(response.user.email_verified, true);
+        assert!(response.user.email_verified);
         assert_eq!(response.user.created_at, "2021-06-25T19:07:33.155Z");
         assert_eq!(response.user.updated_at, "2021-06-25T19:07:33.155Z");
     }
@@ -1,3 +1,11 @@
+mod authentication_method;
+mod impersonator;
+mod organization_membership;
+mod refresh_token;
 mod user;
 
+pub use authentication_method::*;
+pub use impersonator::*;
+pub use organization_membership::*;
+pub use refresh_token::*;
 pub use user::*;
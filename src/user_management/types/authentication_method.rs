@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// The method a [`User`](crate::user_management::User) used to authenticate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum AuthenticationMethod {
+    /// Single Sign-On through an SSO connection.
+    #[serde(rename = "SSO")]
+    Sso,
+
+    /// Email and password.
+    Password,
+
+    /// A passkey.
+    Passkey,
+
+    /// Apple OAuth.
+    #[serde(rename = "AppleOAuth")]
+    AppleOauth,
+
+    /// GitHub OAuth.
+    #[serde(rename = "GitHubOAuth")]
+    GitHubOauth,
+
+    /// Google OAuth.
+    #[serde(rename = "GoogleOAuth")]
+    GoogleOauth,
+
+    /// Microsoft OAuth.
+    #[serde(rename = "MicrosoftOAuth")]
+    MicrosoftOauth,
+
+    /// A one-time code sent by email.
+    MagicAuth,
+
+    /// An admin impersonating the user.
+    Impersonation,
+}
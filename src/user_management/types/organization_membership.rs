@@ -0,0 +1,203 @@
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::organizations::OrganizationId;
+use crate::user_management::UserId;
+use crate::{KnownOrUnknown, Timestamp, Timestamps};
+
+/// The ID of an [`OrganizationMembership`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct OrganizationMembershipId(String);
+
+impl Display for OrganizationMembershipId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for OrganizationMembershipId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for OrganizationMembershipId {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl FromStr for OrganizationMembershipId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
+/// The status of an [`OrganizationMembership`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationMembershipStatus {
+    /// The user is an active member of the organization.
+    Active,
+
+    /// The membership was deactivated, and the user is no longer a member of the
+    /// organization.
+    Inactive,
+
+    /// The user was invited to the organization but has not yet accepted the
+    /// invitation.
+    Pending,
+}
+
+impl OrganizationMembershipStatus {
+    /// Returns the string used to represent the status in the WorkOS API, such as
+    /// `"active"`.
+    pub fn as_api_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Inactive => "inactive",
+            Self::Pending => "pending",
+        }
+    }
+}
+
+impl Display for OrganizationMembershipStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_api_str())
+    }
+}
+
+/// The role of an [`OrganizationMembership`].
+///
+/// [WorkOS Docs: Roles](https://workos.com/docs/user-management/roles-and-permissions)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrganizationMembershipRole {
+    /// The slug of the role, such as `"member"` or `"admin"`.
+    pub slug: String,
+}
+
+/// [WorkOS Docs: Organization Membership](https://workos.com/docs/reference/user-management/organization-membership)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrganizationMembership {
+    /// The ID of the organization membership.
+    pub id: OrganizationMembershipId,
+
+    /// The ID of the [`User`](crate::user_management::User) who is a member of the
+    /// organization.
+    pub user_id: UserId,
+
+    /// The ID of the [`Organization`](crate::organizations::Organization) the user is a
+    /// member of.
+    pub organization_id: OrganizationId,
+
+    /// The status of the organization membership.
+    pub status: KnownOrUnknown<OrganizationMembershipStatus, String>,
+
+    /// The role of the user in the organization.
+    ///
+    /// This is [`None`] if the payload did not include a role.
+    pub role: Option<OrganizationMembershipRole>,
+
+    /// The timestamps for the organization membership.
+    #[serde(flatten)]
+    pub timestamps: Timestamps,
+}
+
+impl OrganizationMembership {
+    /// Returns the timestamp indicating when the organization membership was created.
+    pub fn created_at(&self) -> &Timestamp {
+        &self.timestamps.created_at
+    }
+
+    /// Returns the timestamp indicating when the organization membership was last
+    /// updated.
+    pub fn updated_at(&self) -> &Timestamp {
+        &self.timestamps.updated_at
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_deserializes_an_organization_membership() {
+        let organization_membership: OrganizationMembership = serde_json::from_str(
+            &json!({
+              "object": "organization_membership",
+              "id": "om_01E4ZCR3C56J083X43JQXF3JK5",
+              "user_id": "user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9E",
+              "organization_id": "org_01E4ZCR3C56J083X43JQXF3JK5",
+              "status": "active",
+              "role": {
+                "slug": "admin"
+              },
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            organization_membership,
+            OrganizationMembership {
+                id: OrganizationMembershipId::from("om_01E4ZCR3C56J083X43JQXF3JK5"),
+                user_id: UserId::from("user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9E"),
+                organization_id: OrganizationId::from("org_01E4ZCR3C56J083X43JQXF3JK5"),
+                status: KnownOrUnknown::Known(OrganizationMembershipStatus::Active),
+                role: Some(OrganizationMembershipRole {
+                    slug: "admin".to_string()
+                }),
+                timestamps: Timestamps {
+                    created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+                    updated_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+                },
+            }
+        )
+    }
+
+    #[test]
+    fn it_deserializes_unknown_organization_membership_statuses() {
+        let organization_membership: OrganizationMembership = serde_json::from_str(
+            &json!({
+              "object": "organization_membership",
+              "id": "om_01E4ZCR3C56J083X43JQXF3JK5",
+              "user_id": "user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9E",
+              "organization_id": "org_01E4ZCR3C56J083X43JQXF3JK5",
+              "status": "suspended",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            organization_membership.status,
+            KnownOrUnknown::Unknown("suspended".to_string())
+        )
+    }
+
+    #[test]
+    fn it_displays_the_organization_membership_status_as_it_is_serialized() {
+        for status in [
+            OrganizationMembershipStatus::Active,
+            OrganizationMembershipStatus::Inactive,
+            OrganizationMembershipStatus::Pending,
+        ] {
+            assert_eq!(
+                serde_json::to_value(&status).unwrap(),
+                json!(status.to_string())
+            );
+        }
+    }
+}
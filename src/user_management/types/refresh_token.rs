@@ -0,0 +1,61 @@
+use std::fmt::{Debug, Display};
+
+use serde::{Deserialize, Serialize};
+
+/// A refresh token that may be exchanged for a new session for a
+/// [`User`](crate::user_management::User).
+///
+/// Its [`Debug`] output is redacted so that it is not accidentally logged.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct RefreshToken(String);
+
+impl Debug for RefreshToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RefreshToken").field(&"[REDACTED]").finish()
+    }
+}
+
+impl Display for RefreshToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for RefreshToken {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for RefreshToken {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RefreshToken;
+
+    #[test]
+    fn it_displays_the_refresh_token() {
+        let refresh_token = RefreshToken::from("yAjhKk123NLIjdrBdGZPf8pLIDvK");
+
+        assert_eq!(refresh_token.to_string(), "yAjhKk123NLIjdrBdGZPf8pLIDvK");
+        assert_eq!(
+            refresh_token,
+            RefreshToken::from("yAjhKk123NLIjdrBdGZPf8pLIDvK".to_string())
+        )
+    }
+
+    #[test]
+    fn it_redacts_the_refresh_token_in_debug_output() {
+        let refresh_token = RefreshToken::from("yAjhKk123NLIjdrBdGZPf8pLIDvK");
+
+        assert_eq!(
+            format!("{refresh_token:?}"),
+            r#"RefreshToken("[REDACTED]")"#
+        );
+        assert!(!format!("{refresh_token:#?}").contains("yAjhKk123NLIjdrBdGZPf8pLIDvK"))
+    }
+}
@@ -1,9 +1,11 @@
+use std::convert::Infallible;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
 /// The ID of a [`User`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct UserId(String);
 
 impl Display for UserId {
@@ -24,6 +26,14 @@ impl From<&str> for UserId {
     }
 }
 
+impl FromStr for UserId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
 /// [WorkOS Docs: User](https://workos.com/docs/reference/user-management/user)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
@@ -1,7 +1,13 @@
 //! A module for receiving webhooks from WorkOS.
 //!
 //! When implementing webhooks, be sure to reference the [Webhooks Best Practices](https://workos.com/docs/best-practices/webhooks) guide.
+//!
+//! Verifying webhook signatures is only available when the `crypto` feature is enabled.
 
 mod types;
+#[cfg(feature = "crypto")]
+mod verifier;
 
 pub use types::*;
+#[cfg(feature = "crypto")]
+pub use verifier::*;
@@ -1,9 +1,8 @@
-mod directory;
 mod events;
 mod webhook;
 mod webhook_event;
 
-pub use directory::*;
+pub use crate::directory_sync::{Directory, DirectoryState};
 pub use events::*;
 pub use webhook::*;
 pub use webhook_event::*;
@@ -1,9 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::sso::Connection;
 
 /// [WorkOS Docs: `connection.activated` Webhook](https://workos.com/docs/reference/webhooks/connection#webhooks-sso.connection.activated)
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ConnectionActivatedWebhook(pub Connection);
 
 #[cfg(test)]
@@ -11,7 +11,9 @@ mod test {
     use serde_json::json;
 
     use crate::organizations::OrganizationId;
-    use crate::sso::{ConnectionId, ConnectionState, ConnectionType};
+    use crate::sso::{
+        ConnectionDomain, ConnectionDomainId, ConnectionId, ConnectionState, ConnectionType,
+    };
     use crate::webhooks::{Webhook, WebhookEvent, WebhookId};
     use crate::{KnownOrUnknown, Timestamp, Timestamps};
 
@@ -53,10 +55,16 @@ mod test {
                 id: WebhookId::from("wh_01G699XH8F3MAJJWSHZFQ3WWVX"),
                 event: WebhookEvent::ConnectionActivated(ConnectionActivatedWebhook(Connection {
                     id: ConnectionId::from("conn_01EHWNC0FCBHZ3BJ7EGKYXK0E6"),
+                    object: "connection".to_string(),
                     organization_id: Some(OrganizationId::from("org_01EHWNCE74X7JSDV0X3SZ3KJNY")),
                     r#type: KnownOrUnknown::Known(ConnectionType::OktaSaml),
                     name: "Foo Corp's Connection".to_string(),
                     state: KnownOrUnknown::Known(ConnectionState::Active),
+                    domains: vec![ConnectionDomain {
+                        id: ConnectionDomainId::from("conn_domain_01EHWNFTAFCF3CQAE5A9Q0P1YB"),
+                        domain: "foo-corp.com".to_string(),
+                    }],
+                    attribute_mapping: None,
                     timestamps: Timestamps {
                         created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
                         updated_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap()
@@ -1,9 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::sso::Connection;
 
 /// [WorkOS Docs: `connection.activated` Webhook](https://workos.com/docs/reference/webhooks/connection#webhooks-sso.connection.activated)
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ConnectionDeletedWebhook(pub Connection);
 
 #[cfg(test)]
@@ -44,10 +44,13 @@ mod test {
                 id: WebhookId::from("wh_01G69A9MDSW8MM1XW5S0EHA0NV"),
                 event: WebhookEvent::ConnectionDeleted(ConnectionDeletedWebhook(Connection {
                     id: ConnectionId::from("conn_01EHWNC0FCBHZ3BJ7EGKYXK0E6"),
+                    object: "connection".to_string(),
                     organization_id: Some(OrganizationId::from("org_01EHWNCE74X7JSDV0X3SZ3KJNY")),
                     r#type: KnownOrUnknown::Known(ConnectionType::OktaSaml),
                     name: "Foo Corp's Connection".to_string(),
                     state: KnownOrUnknown::Known(ConnectionState::Inactive),
+                    domains: Vec::new(),
+                    attribute_mapping: None,
                     timestamps: Timestamps {
                         created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
                         updated_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap()
@@ -1,9 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::webhooks::Directory;
 
 /// [WorkOS Docs: `dsync.deleted` Webhook](https://workos.com/docs/reference/webhooks/directory#webhooks-dsync.deleted)
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DirectoryDeletedWebhook(pub Directory);
 
 #[cfg(test)]
@@ -44,9 +44,12 @@ mod test {
                 id: WebhookId::from("wh_03FKJ843CVE8F7BXQSPFH0M53V"),
                 event: WebhookEvent::DirectoryDeleted(DirectoryDeletedWebhook(Directory {
                     id: DirectoryId::from("directory_01EHWNC0FCBHZ3BJ7EGKYXK0E6"),
+                    object: "directory".to_string(),
                     organization_id: Some(OrganizationId::from("org_01EZTR6WYX1A0DSE2CYMGXQ24Y")),
                     r#type: KnownOrUnknown::Known(DirectoryType::GenericScimV2_0),
                     name: "Foo Corp's Directory".to_string(),
+                    domain: None,
+                    external_key: None,
                     state: KnownOrUnknown::Known(DirectoryState::Deleting),
                     timestamps: Timestamps {
                         created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
@@ -1,11 +1,14 @@
+use std::convert::Infallible;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use crate::webhooks::WebhookEvent;
 
 /// The ID of a [`Webhook`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct WebhookId(String);
 
 impl Display for WebhookId {
@@ -26,8 +29,16 @@ impl From<&str> for WebhookId {
     }
 }
 
+impl FromStr for WebhookId {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
 /// A WorkOS webhook.
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Webhook {
     /// The ID of the webhook.
     pub id: WebhookId,
@@ -36,3 +47,141 @@ pub struct Webhook {
     #[serde(flatten)]
     pub event: WebhookEvent,
 }
+
+/// An error returned when converting a [`Webhook`] into a specific event type, such as
+/// [`DirectoryUserCreatedWebhook`](crate::webhooks::DirectoryUserCreatedWebhook), and
+/// the webhook is for a different event.
+///
+/// The original webhook is returned so that it can be converted into another type.
+#[derive(Debug, Error)]
+#[error(
+    "expected a {expected} webhook but received a {} webhook",
+    .webhook.event.event_type()
+)]
+pub struct UnexpectedWebhookEventError {
+    /// The name of the expected event.
+    pub expected: &'static str,
+
+    /// The webhook that was received.
+    pub webhook: Box<Webhook>,
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::webhooks::{ConnectionActivatedWebhook, DirectoryUserUpdatedWebhook};
+
+    use super::*;
+
+    fn assert_round_trips(value: serde_json::Value) {
+        let webhook: Webhook = serde_json::from_value(value).unwrap();
+
+        let serialized = serde_json::to_string(&webhook).unwrap();
+        let deserialized: Webhook = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized, webhook)
+    }
+
+    fn connection_activated_webhook() -> serde_json::Value {
+        json!({
+          "id": "wh_01G699XH8F3MAJJWSHZFQ3WWVX",
+          "event": "connection.activated",
+          "data": {
+            "object": "connection",
+            "id": "conn_01EHWNC0FCBHZ3BJ7EGKYXK0E6",
+            "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+            "connection_type": "OktaSAML",
+            "name": "Foo Corp's Connection",
+            "state": "active",
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+          }
+        })
+    }
+
+    fn directory_user_updated_webhook() -> serde_json::Value {
+        json!({
+          "id": "wh_08FKJ843CVE8F7BXQSPFH0M53V",
+          "event": "dsync.user.updated",
+          "data": {
+            "object": "directory_user",
+            "directory_id": "directory_01E1X194NTJ3PYMAY79DYV0F0P",
+            "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+            "id": "directory_user_01E1X1B89NH8Z3SDFJR4H7RGX7",
+            "idp_id": "8931",
+            "first_name": "Veda",
+            "last_name": "Block",
+            "username": "veda@example.com",
+            "emails": [
+              {
+                "type": "work",
+                "value": "veda@example.com",
+                "primary": true
+              }
+            ],
+            "state": "suspended",
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z",
+            "raw_attributes": {
+              "idp_id": "8931"
+            },
+            "custom_attributes": {
+              "department": "Engineering"
+            },
+            "previous_attributes": {
+              "lastName": "Cube"
+            }
+          }
+        })
+    }
+
+    #[test]
+    fn it_round_trips_a_connection_activated_webhook() {
+        assert_round_trips(connection_activated_webhook())
+    }
+
+    #[test]
+    fn it_round_trips_a_directory_user_updated_webhook() {
+        assert_round_trips(directory_user_updated_webhook())
+    }
+
+    #[test]
+    fn it_returns_the_event_type() {
+        for (value, event_type) in [
+            (connection_activated_webhook(), "connection.activated"),
+            (directory_user_updated_webhook(), "dsync.user.updated"),
+        ] {
+            let webhook: Webhook = serde_json::from_value(value).unwrap();
+
+            assert_eq!(webhook.event.event_type(), event_type);
+            assert_eq!(
+                serde_json::to_value(&webhook).unwrap()["event"],
+                json!(event_type)
+            );
+        }
+    }
+
+    #[test]
+    fn it_converts_a_webhook_into_its_event_type() {
+        let webhook: Webhook = serde_json::from_value(connection_activated_webhook()).unwrap();
+
+        let event = ConnectionActivatedWebhook::try_from(webhook).unwrap();
+
+        assert_eq!(event.0.name, "Foo Corp's Connection")
+    }
+
+    #[test]
+    fn it_returns_the_webhook_when_converting_into_a_different_event_type() {
+        let webhook: Webhook = serde_json::from_value(connection_activated_webhook()).unwrap();
+
+        let err = DirectoryUserUpdatedWebhook::try_from(webhook.clone()).unwrap_err();
+
+        assert_eq!(err.expected, "dsync.user.updated");
+        assert_eq!(*err.webhook, webhook);
+        assert_eq!(
+            err.to_string(),
+            "expected a dsync.user.updated webhook but received a connection.activated webhook"
+        )
+    }
+}
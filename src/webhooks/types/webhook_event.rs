@@ -1,9 +1,11 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::events::*;
+use crate::webhooks::{UnexpectedWebhookEventError, Webhook};
 
 /// The event of a [`Webhook`](crate::webhooks::Webhook).
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 #[serde(tag = "event", content = "data")]
 pub enum WebhookEvent {
     /// [WorkOS Docs: `connection.activated` Webhook](https://workos.com/docs/reference/webhooks/connection#webhooks-sso.connection.activated)
@@ -62,3 +64,65 @@ pub enum WebhookEvent {
     #[serde(rename = "dsync.group.user_removed")]
     DirectoryUserRemovedFromGroup(DirectoryUserRemovedFromGroupWebhook),
 }
+
+impl WebhookEvent {
+    /// Returns the name of the event, such as `dsync.user.created`.
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            Self::ConnectionActivated(_) => "connection.activated",
+            Self::ConnectionDeactivated(_) => "connection.deactivated",
+            Self::ConnectionDeleted(_) => "connection.deleted",
+            Self::DirectoryActivated(_) => "dsync.activated",
+            Self::DirectoryDeactivated(_) => "dsync.deactivated",
+            Self::DirectoryDeleted(_) => "dsync.deleted",
+            Self::DirectoryUserCreated(_) => "dsync.user.created",
+            Self::DirectoryUserUpdated(_) => "dsync.user.updated",
+            Self::DirectoryUserDeleted(_) => "dsync.user.deleted",
+            Self::DirectoryGroupCreated(_) => "dsync.group.created",
+            Self::DirectoryGroupUpdated(_) => "dsync.group.updated",
+            Self::DirectoryGroupDeleted(_) => "dsync.group.deleted",
+            Self::DirectoryUserAddedToGroup(_) => "dsync.group.user_added",
+            Self::DirectoryUserRemovedFromGroup(_) => "dsync.group.user_removed",
+        }
+    }
+}
+
+/// Implements converting a [`Webhook`] into the event type of each of the given
+/// [`WebhookEvent`] variants, returning an [`UnexpectedWebhookEventError`] when the
+/// webhook is for a different event.
+macro_rules! impl_try_from_webhook {
+    ($($variant:ident($event:ty) => $expected:literal,)*) => {
+        $(
+            impl TryFrom<Webhook> for $event {
+                type Error = UnexpectedWebhookEventError;
+
+                fn try_from(webhook: Webhook) -> Result<Self, Self::Error> {
+                    match webhook.event {
+                        WebhookEvent::$variant(event) => Ok(event),
+                        _ => Err(UnexpectedWebhookEventError {
+                            expected: $expected,
+                            webhook: Box::new(webhook),
+                        }),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_webhook! {
+    ConnectionActivated(ConnectionActivatedWebhook) => "connection.activated",
+    ConnectionDeactivated(ConnectionDeactivatedWebhook) => "connection.deactivated",
+    ConnectionDeleted(ConnectionDeletedWebhook) => "connection.deleted",
+    DirectoryActivated(DirectoryActivatedWebhook) => "dsync.activated",
+    DirectoryDeactivated(DirectoryDeactivatedWebhook) => "dsync.deactivated",
+    DirectoryDeleted(DirectoryDeletedWebhook) => "dsync.deleted",
+    DirectoryUserCreated(DirectoryUserCreatedWebhook) => "dsync.user.created",
+    DirectoryUserUpdated(DirectoryUserUpdatedWebhook) => "dsync.user.updated",
+    DirectoryUserDeleted(DirectoryUserDeletedWebhook) => "dsync.user.deleted",
+    DirectoryGroupCreated(DirectoryGroupCreatedWebhook) => "dsync.group.created",
+    DirectoryGroupUpdated(DirectoryGroupUpdatedWebhook) => "dsync.group.updated",
+    DirectoryGroupDeleted(DirectoryGroupDeletedWebhook) => "dsync.group.deleted",
+    DirectoryUserAddedToGroup(DirectoryUserAddedToGroupWebhook) => "dsync.group.user_added",
+    DirectoryUserRemovedFromGroup(DirectoryUserRemovedFromGroupWebhook) => "dsync.group.user_removed",
+}
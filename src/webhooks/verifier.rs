@@ -0,0 +1,590 @@
+use std::fmt::Debug;
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+use thiserror::Error;
+
+use crate::core::crypto::{Hmac, HmacAlgorithm, HmacKey};
+use crate::webhooks::Webhook;
+
+/// An error returned when verifying a webhook.
+#[derive(Debug, Error)]
+pub enum VerifyWebhookError {
+    /// The `WorkOS-Signature` header could not be parsed.
+    #[error("malformed WorkOS-Signature header")]
+    MalformedHeader,
+
+    /// The signature does not match the payload.
+    #[error("webhook signature is invalid")]
+    InvalidSignature,
+
+    /// The timestamp in the signature is further from the current time than the
+    /// verifier's tolerance allows, which may indicate a replayed webhook.
+    #[error("webhook timestamp is outside the tolerance window")]
+    TimestampOutsideTolerance,
+}
+
+/// An error returned when processing a webhook.
+#[derive(Debug, Error)]
+pub enum ProcessWebhookError {
+    /// The webhook could not be verified.
+    #[error(transparent)]
+    Verify(#[from] VerifyWebhookError),
+
+    /// The webhook payload could not be parsed.
+    #[error("failed to parse webhook payload")]
+    Parse(#[from] serde_json::Error),
+}
+
+/// Verifies and parses a webhook, then passes it to `handler`.
+///
+/// This creates a [`WebhookVerifier`] for each call. When processing many webhooks,
+/// create a verifier once and use [`WebhookVerifier::process`] instead.
+///
+/// # Examples
+///
+/// ```
+/// use workos::webhooks::{process_webhook, ProcessWebhookError, WebhookEvent};
+///
+/// # fn run(payload: &[u8], signature_header: &str) -> Result<(), ProcessWebhookError> {
+/// process_webhook(payload, signature_header, "webhook_secret", |webhook| {
+///     match webhook.event {
+///         WebhookEvent::ConnectionActivated(event) => {
+///             println!("Connection {} activated", event.0.id);
+///         }
+///         _ => {}
+///     }
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn process_webhook<R>(
+    payload: &[u8],
+    signature_header: &str,
+    secret: &str,
+    handler: impl FnOnce(Webhook) -> R,
+) -> Result<R, ProcessWebhookError> {
+    WebhookVerifier::new(secret).process(payload, signature_header, handler)
+}
+
+/// A source of the current time, which can be replaced in tests.
+type Clock = fn() -> DateTime<Utc>;
+
+/// Verifies that webhooks were sent by WorkOS using the signature in their
+/// `WorkOS-Signature` header.
+///
+/// A verifier is created once with the webhook secret and can be shared and reused
+/// across requests. Webhooks whose signature timestamp is more than the tolerance
+/// away from the current time are rejected, which defaults to
+/// [`DEFAULT_TOLERANCE`](Self::DEFAULT_TOLERANCE).
+///
+/// [WorkOS Docs: Webhooks](https://workos.com/docs/events/data-syncing/webhooks)
+///
+/// # Examples
+///
+/// ```
+/// use workos::webhooks::WebhookVerifier;
+///
+/// # fn run(payload: &[u8], signature_header: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// let verifier = WebhookVerifier::new("webhook_secret");
+///
+/// verifier.verify(payload, signature_header)?;
+///
+/// let webhook: workos::webhooks::Webhook = serde_json::from_slice(payload)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    key: HmacKey,
+    tolerance: Duration,
+    clock: Clock,
+}
+
+impl Debug for WebhookVerifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookVerifier")
+            .field("tolerance", &self.tolerance)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebhookVerifier {
+    /// The default tolerance for the webhook timestamp, as recommended by WorkOS.
+    pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(180);
+
+    /// Creates a verifier for the given webhook secret.
+    pub fn new(secret: &str) -> Self {
+        Self {
+            key: HmacKey::new(HmacAlgorithm::Sha256, secret.as_bytes()),
+            tolerance: Self::DEFAULT_TOLERANCE,
+            clock: Utc::now,
+        }
+    }
+
+    /// Sets how far the webhook timestamp may be from the current time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use workos::webhooks::WebhookVerifier;
+    ///
+    /// let verifier =
+    ///     WebhookVerifier::new("webhook_secret").with_tolerance(Duration::from_secs(300));
+    /// ```
+    #[must_use]
+    pub fn with_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Replaces the source of the current time.
+    #[cfg(test)]
+    fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Verifies the signature of a webhook whose payload has been fully read.
+    ///
+    /// The payload must be the raw request body, exactly as it was received.
+    pub fn verify(&self, payload: &[u8], signature_header: &str) -> Result<(), VerifyWebhookError> {
+        let mut verification = self.begin(signature_header)?;
+        verification.update(payload);
+        verification.finish()
+    }
+
+    /// Verifies and parses a webhook, then passes it to `handler` and returns its
+    /// result.
+    ///
+    /// The handler is only called if the signature is valid and the payload is a
+    /// [`Webhook`].
+    pub fn process<R>(
+        &self,
+        payload: &[u8],
+        signature_header: &str,
+        handler: impl FnOnce(Webhook) -> R,
+    ) -> Result<R, ProcessWebhookError> {
+        self.verify(payload, signature_header)?;
+
+        let webhook = serde_json::from_slice(payload)?;
+
+        Ok(handler(webhook))
+    }
+
+    /// Begins verifying the signature of a webhook whose payload is read as a stream.
+    ///
+    /// Each chunk of the raw request body is passed to
+    /// [`WebhookVerification::update`] as it is received, so the body does not need
+    /// to be buffered in order to be verified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use workos::webhooks::WebhookVerifier;
+    ///
+    /// # fn run(chunks: Vec<Vec<u8>>, signature_header: &str) -> Result<(), workos::webhooks::VerifyWebhookError> {
+    /// let verifier = WebhookVerifier::new("webhook_secret");
+    ///
+    /// let mut verification = verifier.begin(signature_header)?;
+    /// for chunk in chunks {
+    ///     verification.update(&chunk);
+    /// }
+    /// verification.finish()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn begin(&self, signature_header: &str) -> Result<WebhookVerification, VerifyWebhookError> {
+        let header = SignatureHeader::parse(signature_header)?;
+
+        let timestamp = header
+            .timestamp
+            .parse()
+            .ok()
+            .and_then(|millis| Utc.timestamp_millis_opt(millis).single())
+            .ok_or(VerifyWebhookError::MalformedHeader)?;
+
+        let within_tolerance = (self.clock)()
+            .signed_duration_since(timestamp)
+            .abs()
+            .to_std()
+            .is_ok_and(|elapsed| elapsed <= self.tolerance);
+        if !within_tolerance {
+            return Err(VerifyWebhookError::TimestampOutsideTolerance);
+        }
+
+        let mut hmac = Hmac::with_key(&self.key);
+        hmac.update(header.timestamp.as_bytes());
+        hmac.update(b".");
+
+        Ok(WebhookVerification {
+            hmac,
+            signature: header.signature,
+        })
+    }
+}
+
+/// An in-progress verification of a webhook's signature, created by
+/// [`WebhookVerifier::begin`].
+pub struct WebhookVerification {
+    hmac: Hmac,
+    signature: Vec<u8>,
+}
+
+impl Debug for WebhookVerification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookVerification")
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebhookVerification {
+    /// Adds the next chunk of the raw request body.
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.hmac.update(chunk);
+    }
+
+    /// Completes the verification once the entire request body has been added.
+    pub fn finish(self) -> Result<(), VerifyWebhookError> {
+        if self.hmac.verify(&self.signature) {
+            Ok(())
+        } else {
+            Err(VerifyWebhookError::InvalidSignature)
+        }
+    }
+}
+
+/// The parsed contents of a `WorkOS-Signature` header, which has the form
+/// `t=<timestamp>, v1=<signature>`.
+struct SignatureHeader<'a> {
+    timestamp: &'a str,
+    signature: Vec<u8>,
+}
+
+impl<'a> SignatureHeader<'a> {
+    fn parse(header: &'a str) -> Result<Self, VerifyWebhookError> {
+        let mut timestamp = None;
+        let mut signature = None;
+
+        for part in header.split(',') {
+            match part.trim().split_once('=') {
+                Some(("t", value)) => timestamp = Some(value),
+                Some(("v1", value)) => signature = Some(value),
+                _ => {}
+            }
+        }
+
+        let timestamp = timestamp
+            .filter(|timestamp| {
+                !timestamp.is_empty() && timestamp.bytes().all(|byte| byte.is_ascii_digit())
+            })
+            .ok_or(VerifyWebhookError::MalformedHeader)?;
+
+        let signature = signature
+            .and_then(decode_hex)
+            .ok_or(VerifyWebhookError::MalformedHeader)?;
+
+        Ok(Self {
+            timestamp,
+            signature,
+        })
+    }
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    if !value.len().is_multiple_of(2) || !value.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    (0..value.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&value[index..index + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use serde_json::json;
+
+    use crate::directory_sync::DirectoryId;
+    use crate::sso::ConnectionId;
+    use crate::webhooks::WebhookEvent;
+
+    use super::*;
+
+    const SECRET: &str = "webhook_secret";
+
+    /// The time at which the webhooks in these tests were sent, one second after
+    /// the `1656358542432` timestamp.
+    fn now() -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(1656358543432).unwrap()
+    }
+
+    fn verifier() -> WebhookVerifier {
+        WebhookVerifier::new(SECRET).with_clock(now)
+    }
+
+    fn sign(timestamp: &str, payload: &[u8]) -> String {
+        let mut hmac = Hmac::new(HmacAlgorithm::Sha256, SECRET.as_bytes());
+        hmac.update(format!("{}.", timestamp).as_bytes());
+        hmac.update(payload);
+
+        let signature: String = hmac
+            .finish()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+
+        format!("t={}, v1={}", timestamp, signature)
+    }
+
+    #[test]
+    fn it_verifies_a_valid_signature() {
+        let payload = br#"{"id":"wh_01G69A99Z3JD2YVW5M2HAG0H3Y"}"#;
+        let header = sign("1656358542432", payload);
+
+        let verifier = verifier();
+
+        assert_matches!(verifier.verify(payload, &header), Ok(()))
+    }
+
+    #[test]
+    fn it_reuses_the_verifier_across_multiple_payloads() {
+        let verifier = verifier();
+
+        let payloads: [&[u8]; 3] = [
+            br#"{"id":"wh_01G69A99Z3JD2YVW5M2HAG0H3Y"}"#,
+            br#"{"id":"wh_01G69A99Z3JD2YVW5M2HAG0H3Z"}"#,
+            b"",
+        ];
+
+        for (index, payload) in payloads.iter().enumerate() {
+            let header = sign(&format!("165635854243{}", index), payload);
+
+            assert_matches!(verifier.verify(payload, &header), Ok(()));
+        }
+
+        let header = sign("1656358542432", payloads[0]);
+        assert_matches!(
+            verifier.verify(payloads[1], &header),
+            Err(VerifyWebhookError::InvalidSignature)
+        )
+    }
+
+    #[test]
+    fn it_verifies_a_payload_in_chunks() {
+        let payload = br#"{"id":"wh_01G69A99Z3JD2YVW5M2HAG0H3Y","event":"connection.activated"}"#;
+        let header = sign("1656358542432", payload);
+
+        let verifier = verifier();
+        let mut verification = verifier.begin(&header).unwrap();
+        for chunk in payload.chunks(7) {
+            verification.update(chunk);
+        }
+
+        assert_matches!(verification.finish(), Ok(()))
+    }
+
+    #[test]
+    fn it_rejects_a_tampered_payload() {
+        let header = sign(
+            "1656358542432",
+            br#"{"id":"wh_01G69A99Z3JD2YVW5M2HAG0H3Y"}"#,
+        );
+
+        let verifier = verifier();
+
+        assert_matches!(
+            verifier.verify(br#"{"id":"wh_01G69A99Z3JD2YVW5M2HAG0H3Z"}"#, &header),
+            Err(VerifyWebhookError::InvalidSignature)
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_signature_made_with_a_different_secret() {
+        let payload = br#"{"id":"wh_01G69A99Z3JD2YVW5M2HAG0H3Y"}"#;
+        let header = sign("1656358542432", payload);
+
+        let verifier = WebhookVerifier::new("another_secret").with_clock(now);
+
+        assert_matches!(
+            verifier.verify(payload, &header),
+            Err(VerifyWebhookError::InvalidSignature)
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_malformed_header() {
+        let verifier = verifier();
+
+        for header in [
+            "",
+            "t=1656358542432",
+            "v1=abcdef",
+            "t=, v1=abcdef",
+            "t=1656358542432, v1=xyz",
+            "t=1656358542432, v1=abc",
+        ] {
+            assert_matches!(
+                verifier.verify(b"{}", header),
+                Err(VerifyWebhookError::MalformedHeader)
+            );
+        }
+    }
+
+    #[test]
+    fn it_accepts_a_timestamp_within_the_tolerance() {
+        let payload = br#"{"id":"wh_01G69A99Z3JD2YVW5M2HAG0H3Y"}"#;
+        let verifier = verifier().with_tolerance(Duration::from_secs(60));
+
+        for timestamp in ["1656358483432", "1656358542432", "1656358603432"] {
+            let header = sign(timestamp, payload);
+
+            assert_matches!(verifier.verify(payload, &header), Ok(()));
+        }
+    }
+
+    #[test]
+    fn it_rejects_a_timestamp_outside_the_tolerance() {
+        let payload = br#"{"id":"wh_01G69A99Z3JD2YVW5M2HAG0H3Y"}"#;
+        let verifier = verifier().with_tolerance(Duration::from_secs(60));
+
+        for timestamp in ["1656358483431", "1656358603433"] {
+            let header = sign(timestamp, payload);
+
+            assert_matches!(
+                verifier.verify(payload, &header),
+                Err(VerifyWebhookError::TimestampOutsideTolerance)
+            );
+        }
+    }
+
+    #[test]
+    fn it_uses_the_default_tolerance() {
+        let payload = br#"{"id":"wh_01G69A99Z3JD2YVW5M2HAG0H3Y"}"#;
+        let verifier = verifier();
+
+        let header = sign("1656358363432", payload);
+        assert_matches!(verifier.verify(payload, &header), Ok(()));
+
+        let header = sign("1656358363431", payload);
+        assert_matches!(
+            verifier.verify(payload, &header),
+            Err(VerifyWebhookError::TimestampOutsideTolerance)
+        )
+    }
+
+    fn connection_activated_payload() -> Vec<u8> {
+        json!({
+          "id": "wh_01G699XH8F3MAJJWSHZFQ3WWVX",
+          "event": "connection.activated",
+          "data": {
+            "object": "connection",
+            "id": "conn_01EHWNC0FCBHZ3BJ7EGKYXK0E6",
+            "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+            "connection_type": "OktaSAML",
+            "name": "Foo Corp's Connection",
+            "state": "active",
+            "domains": [],
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+          }
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    fn directory_deleted_payload() -> Vec<u8> {
+        json!({
+          "id": "wh_03FKJ843CVE8F7BXQSPFH0M53V",
+          "event": "dsync.deleted",
+          "data": {
+            "object": "directory",
+            "id": "directory_01EHWNC0FCBHZ3BJ7EGKYXK0E6",
+            "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+            "type": "generic scim v2.0",
+            "state": "deleting",
+            "name": "Foo Corp's Directory",
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+          }
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Handled {
+        ConnectionActivated(ConnectionId),
+        DirectoryDeleted(DirectoryId),
+        Other,
+    }
+
+    fn handle(webhook: Webhook) -> Handled {
+        match webhook.event {
+            WebhookEvent::ConnectionActivated(event) => Handled::ConnectionActivated(event.0.id),
+            WebhookEvent::DirectoryDeleted(event) => Handled::DirectoryDeleted(event.0.id),
+            _ => Handled::Other,
+        }
+    }
+
+    #[test]
+    fn it_dispatches_verified_webhooks_to_the_handler() {
+        let verifier = verifier();
+
+        let payload = connection_activated_payload();
+        let header = sign("1656358542432", &payload);
+        assert_eq!(
+            verifier.process(&payload, &header, handle).unwrap(),
+            Handled::ConnectionActivated(ConnectionId::from("conn_01EHWNC0FCBHZ3BJ7EGKYXK0E6"))
+        );
+
+        let payload = directory_deleted_payload();
+        let header = sign("1656358542432", &payload);
+        assert_eq!(
+            verifier.process(&payload, &header, handle).unwrap(),
+            Handled::DirectoryDeleted(DirectoryId::from("directory_01EHWNC0FCBHZ3BJ7EGKYXK0E6"))
+        );
+    }
+
+    #[test]
+    fn it_processes_a_webhook_with_the_current_time() {
+        let payload = connection_activated_payload();
+        let header = sign(&Utc::now().timestamp_millis().to_string(), &payload);
+
+        assert_eq!(
+            process_webhook(&payload, &header, SECRET, handle).unwrap(),
+            Handled::ConnectionActivated(ConnectionId::from("conn_01EHWNC0FCBHZ3BJ7EGKYXK0E6"))
+        )
+    }
+
+    #[test]
+    fn it_does_not_call_the_handler_for_an_invalid_signature() {
+        let payload = connection_activated_payload();
+        let header = sign("1656358542432", &directory_deleted_payload());
+
+        let result = verifier().process(&payload, &header, |_| {
+            panic!("the handler should not be called");
+        });
+
+        assert_matches!(
+            result,
+            Err(ProcessWebhookError::Verify(
+                VerifyWebhookError::InvalidSignature
+            ))
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_for_a_verified_payload_that_is_not_a_webhook() {
+        let payload = br#"{"id":"wh_01G69A99Z3JD2YVW5M2HAG0H3Y"}"#;
+        let header = sign("1656358542432", payload);
+
+        let result = verifier().process(payload, &header, |_| ());
+
+        assert_matches!(result, Err(ProcessWebhookError::Parse(_)))
+    }
+}